@@ -0,0 +1,109 @@
+use crate::audio::AudioPlayer;
+use crate::model::{Music, SoundEffect};
+use crate::resource_loader::Resources;
+use crate::storage::Settings;
+
+/// Plays the named sounds through the audio player, applying the player volume settings.
+/// Sounds missing from the sound data are skipped, so the game plays on without them.
+pub struct AudioManager {
+    player: Box<dyn AudioPlayer>,
+    volume: f64,
+    muted: bool,
+    music: Option<Music>,
+    /// The volume of the current music, as set by it's sound data.
+    music_volume: f64,
+}
+
+impl AudioManager {
+    pub fn new(player: Box<dyn AudioPlayer>) -> Self {
+        AudioManager {
+            player,
+            volume: 1.0,
+            muted: false,
+            music: None,
+            music_volume: 1.0,
+        }
+    }
+
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.set_muted(settings.muted);
+        self.set_volume(settings.volume);
+    }
+
+    pub fn play_effect(&mut self, resources: &Resources, effect: SoundEffect) {
+        if self.muted {
+            return;
+        }
+
+        if let Some(sound) = resources.get_sound(effect.value()) {
+            self.player
+                .play_effect(&sound.src, self.output_volume(sound.volume));
+        }
+    }
+
+    /// Loops the given music, kept playing when it's already the current one.
+    pub fn play_music(&mut self, resources: &Resources, music: Music) {
+        if self.music == Some(music) {
+            return;
+        }
+
+        self.music = Some(music);
+
+        match resources.get_sound(music.value()) {
+            Some(sound) => {
+                self.music_volume = sound.volume;
+                self.player
+                    .play_music(&sound.src, self.output_volume(sound.volume));
+            }
+            None => self.player.stop_music(),
+        }
+    }
+
+    pub fn pause_music(&mut self, paused: bool) {
+        self.player.pause_music(paused);
+    }
+
+    pub fn stop_music(&mut self) {
+        self.music = None;
+        self.player.stop_music();
+    }
+
+    /// Sets the volume (0 - 1) all sounds are played relatively to.
+    pub fn set_volume(&mut self, volume: f64) {
+        self.volume = volume.clamp(0.0, 1.0);
+
+        self.update_music_volume();
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+
+        self.update_music_volume();
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// The music currently played, if any.
+    pub fn music(&self) -> Option<Music> {
+        self.music
+    }
+
+    fn update_music_volume(&mut self) {
+        let volume = self.output_volume(self.music_volume);
+
+        self.player.set_music_volume(volume);
+    }
+
+    fn output_volume(&self, volume: f64) -> f64 {
+        match self.muted {
+            true => 0.0,
+            false => volume * self.volume,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+mod audio_manager;
+mod silent_audio;
+mod web_audio;
+
+pub use audio_manager::AudioManager;
+pub use silent_audio::SilentAudio;
+pub use web_audio::WebAudio;
+
+/// The sound output of the game, allowing the game to run with or without an actual audio device.
+pub trait AudioPlayer {
+    fn play_effect(&mut self, src: &str, volume: f64);
+
+    /// Replaces the current music, looping the given one.
+    fn play_music(&mut self, src: &str, volume: f64);
+
+    fn pause_music(&mut self, paused: bool);
+
+    fn stop_music(&mut self);
+
+    fn set_music_volume(&mut self, volume: f64);
+}
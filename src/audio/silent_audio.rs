@@ -0,0 +1,16 @@
+use crate::audio::AudioPlayer;
+
+/// Audio player used when running without a browser (e.g tests / simulations), plays nothing.
+pub struct SilentAudio;
+
+impl AudioPlayer for SilentAudio {
+    fn play_effect(&mut self, _src: &str, _volume: f64) {}
+
+    fn play_music(&mut self, _src: &str, _volume: f64) {}
+
+    fn pause_music(&mut self, _paused: bool) {}
+
+    fn stop_music(&mut self) {}
+
+    fn set_music_volume(&mut self, _volume: f64) {}
+}
@@ -0,0 +1,79 @@
+use js_sys::Promise;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::HtmlAudioElement;
+
+use crate::audio::AudioPlayer;
+
+/// Plays through browser audio elements.
+pub struct WebAudio {
+    music: Option<HtmlAudioElement>,
+}
+
+impl WebAudio {
+    pub fn new() -> Self {
+        WebAudio { music: None }
+    }
+
+    fn create_audio(src: &str, volume: f64) -> Option<HtmlAudioElement> {
+        let audio = HtmlAudioElement::new_with_src(src).ok()?;
+        audio.set_volume(volume);
+
+        Some(audio)
+    }
+
+    /// Browsers reject playing before the player first interacts with the page,
+    /// such rejections are dropped as the music is resumed upon the next interaction.
+    fn play(audio: &HtmlAudioElement) {
+        if let Ok(promise) = audio.play() {
+            Self::ignore_rejection(promise);
+        }
+    }
+
+    fn ignore_rejection(promise: Promise) {
+        spawn_local(async move {
+            JsFuture::from(promise).await.ok();
+        });
+    }
+}
+
+impl AudioPlayer for WebAudio {
+    fn play_effect(&mut self, src: &str, volume: f64) {
+        // Each effect plays on it's own element, so the same effect can overlap itself.
+        if let Some(effect) = Self::create_audio(src, volume) {
+            Self::play(&effect);
+        }
+    }
+
+    fn play_music(&mut self, src: &str, volume: f64) {
+        self.stop_music();
+
+        self.music = Self::create_audio(src, volume);
+
+        if let Some(music) = &self.music {
+            music.set_loop(true);
+            Self::play(music);
+        }
+    }
+
+    fn pause_music(&mut self, paused: bool) {
+        match &self.music {
+            Some(music) if paused => {
+                music.pause().ok();
+            }
+            Some(music) if music.paused() => Self::play(music),
+            _ => {}
+        }
+    }
+
+    fn stop_music(&mut self) {
+        if let Some(music) = self.music.take() {
+            music.pause().ok();
+        }
+    }
+
+    fn set_music_volume(&mut self, volume: f64) {
+        if let Some(music) = &self.music {
+            music.set_volume(volume);
+        }
+    }
+}
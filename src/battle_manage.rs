@@ -1,7 +1,9 @@
+use std::cmp::Ordering;
+
 use itertools::Itertools;
 
 use crate::game::Game;
-use crate::model::{AttackEffect, BehaviorType, SpriteType};
+use crate::model::{AttackEffect, BehaviorType, SpriteType, Trajectory};
 use crate::sprite::{BehaviorManager, Collision, CollisionState, DrawingState, Sprite};
 
 struct CollisionMutation {
@@ -9,6 +11,7 @@ struct CollisionMutation {
     target_id: String,
     damage: f64,
     attack_effect: Option<AttackEffect>,
+    trajectory: Trajectory,
 }
 
 impl CollisionMutation {
@@ -17,12 +20,14 @@ impl CollisionMutation {
         target_id: &String,
         damage: f64,
         attack_effect: Option<AttackEffect>,
+        trajectory: Trajectory,
     ) -> Self {
         CollisionMutation {
             attacking_id: String::from(attacking_id),
             target_id: String::from(target_id),
             attack_effect,
             damage,
+            trajectory,
         }
     }
 }
@@ -31,17 +36,19 @@ pub struct BattleManager;
 
 impl BattleManager {
     pub fn manage_fight(game: &mut Game) {
-        let mutations = Self::collect_collision_mutations(game);
+        let now = game.game_time.time;
+        let mutations = Self::collect_collision_mutations(game, now);
 
-        Self::flag_collision_state(game, mutations);
+        Self::flag_collision_state(game, mutations, now);
     }
 
-    fn flag_collision_state(game: &mut Game, mutations: Vec<CollisionMutation>) {
+    fn flag_collision_state(game: &mut Game, mutations: Vec<CollisionMutation>, now: f64) {
         game.sprites
             .iter_mut()
             .filter(|sprite| sprite.get_collision().is_some())
             .for_each(|sprite| {
                 let sprite_id = sprite.id.clone();
+                let passing = sprite.attack_state.passing;
 
                 let mutations = mutations
                     .iter()
@@ -50,6 +57,15 @@ impl BattleManager {
                     })
                     .collect::<Vec<&CollisionMutation>>();
 
+                // Bites dealt along the attack are taken, eating starts over once the attack is over.
+                match mutations
+                    .iter()
+                    .any(|mutation| mutation.attacking_id == sprite.id)
+                {
+                    true => sprite.attack_state.bite(now, sprite.drawing_state.chilled),
+                    false => sprite.attack_state.stop_eating(),
+                }
+
                 let collision = BehaviorManager::get_sprite_behavior(sprite, BehaviorType::Collision)
                     .as_any()
                     .downcast_mut::<Collision>()
@@ -62,25 +78,37 @@ impl BattleManager {
                         }
 
                         if mutation.target_id == sprite_id {
+                            // Effects are carried along the damage, unless the attacker does no harm.
                             collision.state = match mutation.attack_effect {
-                                Some(effect) => CollisionState::ApplyEffect(effect),
-                                None => CollisionState::TakingDamage(mutation.damage),
+                                Some(effect) if mutation.damage <= 0.0 => {
+                                    CollisionState::ApplyEffect(effect)
+                                }
+                                effect => CollisionState::TakingDamage(
+                                    mutation.damage,
+                                    effect,
+                                    mutation.trajectory,
+                                ),
                             }
                         }
                     })
                 } else {
-                    collision.state = CollisionState::None;
+                    collision.state = match passing {
+                        true => CollisionState::Passing,
+                        false => CollisionState::None,
+                    };
                 }
             });
     }
 
-    fn collect_collision_mutations(game: &mut Game) -> Vec<CollisionMutation> {
+    fn collect_collision_mutations(game: &mut Game, now: f64) -> Vec<CollisionMutation> {
         let mut mutations: Vec<CollisionMutation> = vec![];
 
         game.sprites
             .iter()
             .sorted_by(|a, b| a.board_location.row.cmp(&b.board_location.row))
-            .filter(|sprite| sprite.visible && Self::has_collision_behavior(sprite))
+            .filter(|sprite| {
+                sprite.visible && !sprite.attack_state.passing && Self::has_collision_behavior(sprite)
+            })
             .group_by(|sprite| sprite.board_location.row)
             .into_iter()
             .map(|(_, items)| items.collect::<Vec<&Sprite>>())
@@ -94,17 +122,21 @@ impl BattleManager {
                         .filter(|other| Self::can_collide(sprite, other))
                         .collect::<Vec<&&Sprite>>();
 
-                    // For each candidate, Check if collided
+                    // Out of the collided candidates, a single target is picked
                     let collided_candidate = candidates
                         .iter()
-                        .find(|candidate| Self::has_collision(sprite, candidate));
+                        .filter(|candidate| Self::has_collision(sprite, candidate))
+                        .min_by(|a, b| Self::compare_targets(sprite, a, b));
 
                     if let Some(collided_sprite) = collided_candidate {
                         mutations.push(CollisionMutation::new(
                             &sprite.id,
                             &collided_sprite.id,
-                            sprite.attack_state.get_damage(),
+                            sprite
+                                .attack_state
+                                .get_attack_damage(now, sprite.drawing_state.chilled),
                             sprite.attack_state.effect,
+                            sprite.attack_state.trajectory,
                         ));
                     }
                 });
@@ -136,13 +168,61 @@ impl BattleManager {
     }
 
     fn has_collision(sprite: &Sprite, target: &Sprite) -> bool {
-        let collision = sprite.get_collision().unwrap_or_default();
+        let hitbox = HitBox::from(sprite);
+        let target_hitbox = HitBox::from(target);
 
-        let collision_left = sprite.position.left + collision.left as f64;
+        hitbox.overlaps(&target_hitbox)
+    }
+
+    /// Orders collided targets by the one to be hit first.
+    /// Plants of upper layers (e.g a shell wrapping a plant) shield the ones beneath,
+    /// as do Sprites drawn over others, otherwise the nearest one is hit.
+    /// Sprite ids settle the rest, keeping the choice independent of the sprites order.
+    fn compare_targets(sprite: &Sprite, a: &Sprite, b: &Sprite) -> Ordering {
+        let center = HitBox::from(sprite).center();
+        let distance = |target: &Sprite| (HitBox::from(target).center() - center).abs();
+
+        b.layer
+            .cmp(&a.layer)
+            .then_with(|| b.order.cmp(&a.order))
+            .then_with(|| distance(a).total_cmp(&distance(b)))
+            .then_with(|| a.id.cmp(&b.id))
+    }
+}
 
-        let target_cell = DrawingState::get_active_cell(target);
+/// The area a Sprite collides within, being it's active cell shrunk by it's collision margin.
+struct HitBox {
+    left: f64,
+    right: f64,
+    top: f64,
+    bottom: f64,
+}
+
+impl From<&Sprite> for HitBox {
+    fn from(sprite: &Sprite) -> Self {
+        let margin = sprite.get_collision().unwrap_or_default();
+        let cell = DrawingState::get_active_cell(sprite);
+        let scale = sprite.drawing_state.scale;
+        let position = sprite.position;
+
+        HitBox {
+            left: position.left + margin.left as f64,
+            right: position.left + cell.width * scale - margin.right as f64,
+            top: position.top + margin.top as f64,
+            bottom: position.top + cell.height * scale - margin.bottom as f64,
+        }
+    }
+}
+
+impl HitBox {
+    fn overlaps(&self, other: &HitBox) -> bool {
+        self.left <= other.right
+            && self.right >= other.left
+            && self.top <= other.bottom
+            && self.bottom >= other.top
+    }
 
-        collision_left >= target.position.left
-            && collision_left <= target.position.left + target_cell.width
+    fn center(&self) -> f64 {
+        (self.left + self.right) / 2.0
     }
 }
@@ -0,0 +1,144 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::board::ROW_Y_COORD;
+use crate::game::Game;
+use crate::model::{CombatData, LaneData, ShootDirection, SoundEffect, SpriteType};
+use crate::resource_loader::ResourceKind;
+use crate::scene::BattleScene;
+use crate::sprite::{DrawingState, Sprite};
+
+/// A projectile of a shot yet to be fired, as projectiles of a single shot are staggered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingProjectile {
+    pub plant_id: String,
+    pub bullet: String,
+    pub lane: LaneData,
+    pub fire_time: f64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CombatState {
+    pub pending_projectiles: Vec<PendingProjectile>,
+}
+
+impl CombatState {
+    pub fn new() -> Self {
+        CombatState {
+            pending_projectiles: vec![],
+        }
+    }
+}
+
+/// Shoots plants projectiles, by their combat data.
+pub struct CombatManager;
+
+impl CombatManager {
+    /// Fires staggered projectiles once their time comes, as long as their plant is still standing.
+    pub fn tick(game: &mut Game) {
+        let now = game.game_time.time;
+
+        if game.state.combat_state.pending_projectiles.is_empty() {
+            return;
+        }
+
+        let (due, pending): (Vec<PendingProjectile>, Vec<PendingProjectile>) = game
+            .state
+            .combat_state
+            .pending_projectiles
+            .drain(..)
+            .partition(|projectile| projectile.fire_time <= now);
+
+        game.state.combat_state.pending_projectiles = pending;
+
+        for projectile in due {
+            if Self::is_standing(game, &projectile.plant_id) {
+                Self::fire(
+                    game,
+                    &projectile.plant_id,
+                    &projectile.bullet,
+                    &projectile.lane,
+                );
+            }
+        }
+    }
+
+    /// Shoots along each of the plant lanes which has a zombie to shoot at.
+    pub fn shoot(game: &mut Game, plant_id: &String) {
+        let now = game.game_time.time;
+        let plant_name = game.get_sprite_by_id(plant_id).name.clone();
+
+        let combat = match game
+            .resources
+            .get_resource(&plant_name, &ResourceKind::Plant)
+            .data
+            .combat
+        {
+            Some(combat) => combat,
+            None => return,
+        };
+
+        for lane in &combat.lanes {
+            if !Self::has_target(game, plant_id, &combat, lane) {
+                continue;
+            }
+
+            Self::fire(game, plant_id, &combat.bullet, lane);
+
+            // Following projectiles of the shot are fired one after the other.
+            (1..lane.projectiles).for_each(|index| {
+                game.state
+                    .combat_state
+                    .pending_projectiles
+                    .push(PendingProjectile {
+                        plant_id: plant_id.clone(),
+                        bullet: combat.bullet.clone(),
+                        lane: lane.clone(),
+                        fire_time: now + combat.stagger * index as f64,
+                    })
+            });
+        }
+    }
+
+    fn fire(game: &mut Game, plant_id: &String, bullet: &str, lane: &LaneData) {
+        BattleScene::create_bullet(game, plant_id, bullet, lane);
+        game.play_sound(SoundEffect::Shoot);
+    }
+
+    /// Whether a living zombie stands within the lane row, the plant range and, if required, ahead of the plant.
+    fn has_target(game: &mut Game, plant_id: &String, combat: &CombatData, lane: &LaneData) -> bool {
+        let plant = game.get_sprite_by_id(plant_id);
+        let plant_col = plant.board_location.col as i32;
+        let plant_center = Self::center(plant);
+        let lane_row = plant.board_location.row as i32 + lane.row_offset;
+
+        // Lanes off the board have nothing to shoot at.
+        if lane_row < 1 || lane_row >= ROW_Y_COORD.len() as i32 {
+            return false;
+        }
+
+        game.sprites.iter().any(|sprite| {
+            let is_ahead = match lane.direction {
+                ShootDirection::Forward => Self::center(sprite) >= plant_center,
+                ShootDirection::Back => Self::center(sprite) <= plant_center,
+            };
+            let cols_away = (sprite.board_location.col as i32 - plant_col).unsigned_abs() as usize;
+
+            sprite.visible
+                && !sprite.attack_state.is_dead()
+                && sprite.sprite_type == SpriteType::Zombie
+                && sprite.board_location.row as i32 == lane_row
+                && (!combat.only_ahead || is_ahead)
+                && (combat.range == 0 || cols_away <= combat.range)
+        })
+    }
+
+    fn is_standing(game: &Game, plant_id: &String) -> bool {
+        game.sprites
+            .iter()
+            .any(|sprite| &sprite.id == plant_id && sprite.visible)
+    }
+
+    fn center(sprite: &Sprite) -> f64 {
+        sprite.position.left + DrawingState::get_active_cell(sprite).width / 2.0
+    }
+}
@@ -7,3 +7,12 @@ pub const CANVAS_WIDTH_F64: f64 = CANVAS_WIDTH as f64;
 pub const CANVAS_HEIGHT_F64: f64 = CANVAS_HEIGHT as f64;
 
 pub const MAX_LAWN_CLEANERS_LOST: usize = 3;
+
+/// Speed factor of chilled Sprites movement and animation.
+pub const SLOW_FACTOR: f64 = 0.5;
+
+/// Time (ms) a chilled zombie stays slow since it was last hit.
+pub const SLOW_DURATION: f64 = 10.0 * 1000.0;
+
+/// Distance (px) a vaulting zombie leaps over the plant it reaches.
+pub const VAULT_DISTANCE: f64 = 100.0;
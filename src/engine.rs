@@ -1,30 +1,49 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use js_sys::Math;
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::spawn_local;
-use web_sys::MouseEvent;
+use web_sys::{HtmlCanvasElement, KeyboardEvent, PointerEvent};
 
+use crate::audio::WebAudio;
 use crate::game::Game;
-use crate::model::GameMouseEvent;
+use crate::model::{GameKeyEvent, GameMouseEvent, Position};
+use crate::painter::Painter;
+use crate::random::Random;
 use crate::resource_loader::ResourceLoader;
-use crate::web_utils::request_animation_frame;
+use crate::storage::LocalStorage;
+use crate::timers::WindowClock;
+use crate::web_utils::{query_param, request_animation_frame, window};
 
 pub struct Engine {
     game: Rc<RefCell<Game>>,
-    handled_events: Vec<GameMouseEvent>,
+    canvas: HtmlCanvasElement,
+    handled_events: Vec<&'static str>,
 }
 
 impl Default for Engine {
     fn default() -> Self {
+        let painter = Painter::new();
+        let canvas = painter.canvas.clone();
+        let seed = (Math::random() * u64::MAX as f64) as u64;
+
         Engine {
-            game: Rc::new(RefCell::new(Game::new())),
+            game: Rc::new(RefCell::new(Game::new(
+                Box::new(painter),
+                Box::new(WindowClock::new()),
+                Box::new(LocalStorage),
+                Box::new(WebAudio::new()),
+                Random::new(seed),
+            ))),
+            canvas,
             handled_events: vec![
-                GameMouseEvent::MouseMove,
-                GameMouseEvent::MouseUp,
-                GameMouseEvent::MouseLeave,
-                GameMouseEvent::MouseDown,
+                "pointermove",
+                "pointerup",
+                "pointercancel",
+                "pointerleave",
+                "pointerdown",
             ],
         }
     }
@@ -35,6 +54,15 @@ pub enum EngineError {
     IO(std::io::Error),
     Js(JsValue),
     SerdeParsing(serde_wasm_bindgen::Error),
+    JsonParsing(serde_json::Error),
+    /// A save stored by a newer version of the game.
+    SaveVersion(u32),
+}
+
+impl From<std::io::Error> for EngineError {
+    fn from(e: std::io::Error) -> Self {
+        EngineError::IO(e)
+    }
 }
 
 impl From<JsValue> for EngineError {
@@ -49,12 +77,22 @@ impl From<serde_wasm_bindgen::Error> for EngineError {
     }
 }
 
+impl From<serde_json::Error> for EngineError {
+    fn from(e: serde_json::Error) -> Self {
+        EngineError::JsonParsing(e)
+    }
+}
+
 impl From<EngineError> for JsValue {
     fn from(e: EngineError) -> Self {
         match e {
             EngineError::Js(e) => e,
             EngineError::SerdeParsing(e) => JsValue::from_str(&e.to_string()),
             EngineError::IO(e) => JsValue::from_str(&e.to_string()),
+            EngineError::JsonParsing(e) => JsValue::from_str(&e.to_string()),
+            EngineError::SaveVersion(version) => {
+                JsValue::from_str(&format!("Unsupported save version {}", version))
+            }
         }
     }
 }
@@ -67,9 +105,28 @@ impl Engine {
             // Load resources
             let game_resources = ResourceLoader::load(&ResourceLoader).await;
 
+            // Levels can be replayed by a reported seed, e.g `?seed=1234`
+            engine.game.borrow_mut().level_seed =
+                query_param("seed").and_then(|seed| seed.parse::<u64>().ok());
+
             // Init game
             engine.game.borrow_mut().init(game_resources);
 
+            // Continues the battle saved upon pausing, e.g `?continue=true`
+            if query_param("continue").is_some() {
+                engine.game.borrow_mut().continue_saved_battle();
+            }
+
+            // Plays the last finished level back, e.g `?replay=true`
+            if query_param("replay").is_some() {
+                engine.game.borrow_mut().play_last_replay();
+            }
+
+            // Game speed can be tuned for inspecting fights, e.g `?speed=0.5`
+            if let Some(speed) = query_param("speed").and_then(|speed| speed.parse::<f64>().ok()) {
+                engine.game.borrow_mut().set_speed(speed);
+            }
+
             // Attach game listeners
             engine.register_events();
 
@@ -81,25 +138,51 @@ impl Engine {
     fn register_events(&self) {
         self.handled_events
             .iter()
-            .for_each(|event| self.listen_event(*event));
+            .for_each(|event| self.listen_event(event));
+
+        self.listen_keyboard();
     }
 
-    fn listen_event(&self, name: GameMouseEvent) {
+    fn listen_event(&self, event_type: &'static str) {
         let game_closure_ref = Rc::clone(&self.game);
+        let game_event = GameMouseEvent::from_pointer_event(event_type)
+            .unwrap_or_else(|| panic!("Unsupported pointer event - {}", event_type));
+
+        let closure = Closure::wrap(Box::new(move |event: PointerEvent| {
+            // Multi-touch is ignored, only the first finger acts as a mouse.
+            if !event.is_primary() {
+                return;
+            }
 
-        let closure = Closure::wrap(Box::new(move |event: MouseEvent| {
             game_closure_ref
                 .borrow_mut()
-                .handle_mouse_event(name, event);
+                .handle_mouse_event(game_event, Position::from_event(&event));
+        }) as Box<dyn FnMut(_)>);
+
+        self.canvas
+            .add_event_listener_with_callback(event_type, closure.as_ref().unchecked_ref())
+            .unwrap();
+
+        closure.forget();
+    }
+
+    fn listen_keyboard(&self) {
+        let game_closure_ref = Rc::clone(&self.game);
+
+        let closure = Closure::wrap(Box::new(move |event: KeyboardEvent| {
+            // Held keys repeat their keydown, each key press is taken once.
+            if event.repeat() {
+                return;
+            }
+
+            if let Some(key_event) = GameKeyEvent::from_key(&event.key()) {
+                game_closure_ref.borrow_mut().handle_key_event(key_event);
+            }
         }) as Box<dyn FnMut(_)>);
 
-        self.game
-            .borrow()
-            .canvas()
-            .add_event_listener_with_callback(
-                &name.to_string().to_lowercase(),
-                closure.as_ref().unchecked_ref(),
-            )
+        // Canvas is not focusable, keys are listened upon the entire window.
+        window()
+            .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
             .unwrap();
 
         closure.forget();
@@ -1,40 +1,55 @@
-use std::sync::Mutex;
+use std::cell::RefCell;
 
 #[derive(Debug, Default)]
 pub struct GameFeatures {
     pub draw_board_lines: bool,
     pub update_sun_score: bool,
     pub generate_sun: bool,
+    pub spawn_waves: bool,
 }
 
 impl GameFeatures {
     pub fn show_board_lines() -> bool {
-        FEATURES.lock().unwrap().draw_board_lines
+        FEATURES.with(|features| features.borrow().draw_board_lines)
     }
 
     pub fn enable_board_lines(enabled: bool) {
-        FEATURES.lock().unwrap().draw_board_lines = enabled
+        FEATURES.with(|features| features.borrow_mut().draw_board_lines = enabled)
     }
 
     pub fn should_update_sun_score() -> bool {
-        FEATURES.lock().unwrap().update_sun_score
+        FEATURES.with(|features| features.borrow().update_sun_score)
     }
 
     pub fn enable_update_sun_score(enabled: bool) {
-        FEATURES.lock().unwrap().update_sun_score = enabled
+        FEATURES.with(|features| features.borrow_mut().update_sun_score = enabled)
     }
 
     pub fn should_generate_sun() -> bool {
-        FEATURES.lock().unwrap().generate_sun
+        FEATURES.with(|features| features.borrow().generate_sun)
     }
 
     pub fn enable_generate_sun(enabled: bool) {
-        FEATURES.lock().unwrap().generate_sun = enabled
+        FEATURES.with(|features| features.borrow_mut().generate_sun = enabled)
+    }
+
+    pub fn should_spawn_waves() -> bool {
+        FEATURES.with(|features| features.borrow().spawn_waves)
+    }
+
+    pub fn enable_spawn_waves(enabled: bool) {
+        FEATURES.with(|features| features.borrow_mut().spawn_waves = enabled)
     }
 }
 
-pub static FEATURES: Mutex<GameFeatures> = Mutex::new(GameFeatures {
-    draw_board_lines: false,
-    update_sun_score: false,
-    generate_sun: false,
-});
+// Features are kept per thread, so simultaneous headless games (e.g tests) won't affect each other.
+thread_local! {
+    pub static FEATURES: RefCell<GameFeatures> = const {
+        RefCell::new(GameFeatures {
+            draw_board_lines: false,
+            update_sun_score: false,
+            generate_sun: false,
+            spawn_waves: false,
+        })
+    };
+}
@@ -1,43 +1,76 @@
-use web_sys::{HtmlCanvasElement, MouseEvent};
-
+use crate::audio::{AudioManager, AudioPlayer};
 use crate::battle_manage::BattleManager;
 use crate::board::{Board, BoardLocation};
+use crate::combat_manager::CombatManager;
 use crate::constants::MAX_LAWN_CLEANERS_LOST;
 use crate::features::GameFeatures;
 use crate::fps::Fps;
 use crate::log;
 use crate::model::{
-    BehaviorType, Callback, GameInteraction, GameMouseEvent, GameState, Position, SpriteType,
+    BehaviorType, Callback, GameInteraction, GameKeyEvent, GameMouseEvent, GameState, LevelData,
+    Music, PlantLayer, Position, SoundEffect, SpriteType,
 };
-use crate::painter::Painter;
+use crate::painter::Renderer;
+use crate::random::Random;
+use crate::recharge_manager::RechargeManager;
+use crate::replay::{Replay, ReplayInput, ReplayPlayer};
 use crate::resource_loader::Resources;
-use crate::scene::{BattleScene, HomeScene, PlantsChooser};
+use crate::rules_manager::RulesManager;
+use crate::scene::{BattleScene, ChallengeSelect, HomeScene, LevelSelect, PlantsChooser};
+use crate::snapshot::BattleSnapshot;
 use crate::sprite::{BehaviorManager, Sprite};
+use crate::storage::{SaveData, Storage};
 use crate::sun_manager::SunManager;
-use crate::timers::GameTime;
+use crate::survival_manager::{SurvivalManager, SURVIVAL_LEVEL};
+use crate::timers::{Clock, GameTime, GAME_SPEEDS};
+use crate::wave_manager::WaveManager;
 
 pub struct Game {
     pub resources: Resources,
-    pub painter: Painter,
+    pub painter: Box<dyn Renderer>,
+    pub audio: AudioManager,
     pub game_time: GameTime,
+    pub random: Random,
+    /// A fixed seed to start levels with (e.g when replaying a reported bug), otherwise a new one is drawn.
+    pub level_seed: Option<u64>,
     pub mouse_position: Position,
     pub sprites: Vec<Sprite>,
     pub state: GameState,
+    /// The persisted player progress and settings.
+    pub save_data: SaveData,
+    /// The recording of the currently played level.
+    pub replay: Option<Replay>,
+    /// The replay played back, taking the place of the user inputs meanwhile.
+    replay_player: Option<ReplayPlayer>,
     fps: Fps,
+    storage: Box<dyn Storage>,
 
     last_gc: f64,
 }
 
 impl Game {
-    pub fn new() -> Game {
+    pub fn new(
+        painter: Box<dyn Renderer>,
+        clock: Box<dyn Clock>,
+        storage: Box<dyn Storage>,
+        audio: Box<dyn AudioPlayer>,
+        random: Random,
+    ) -> Game {
         Game {
-            painter: Painter::new(),
+            painter,
+            audio: AudioManager::new(audio),
             resources: Resources::new(),
-            game_time: GameTime::new(),
+            game_time: GameTime::new(clock),
+            random,
+            level_seed: None,
             state: GameState::new(),
+            save_data: SaveData::default(),
             fps: Fps::new(),
+            storage,
             mouse_position: Position::new(0.0, 0.0),
             sprites: vec![],
+            replay: None,
+            replay_player: None,
             last_gc: 0.0,
         }
     }
@@ -45,6 +78,11 @@ impl Game {
     pub fn init(&mut self, resources: Resources) {
         self.resources = resources;
 
+        // Restore the saved progress and settings
+        self.save_data = SaveData::load(self.storage.as_ref());
+        self.game_time.set_speed(self.save_data.settings.speed);
+        self.audio.apply_settings(&self.save_data.settings);
+
         // Start game internal time
         self.game_time.start();
 
@@ -54,22 +92,36 @@ impl Game {
         self.last_gc = self.game_time.time;
     }
 
+    #[allow(dead_code)]
     pub fn init_debug_mode(&mut self, resource: Resources) {
         self.init(resource);
 
-        self.select_level();
+        self.select_level("1-1");
 
         GameFeatures::enable_board_lines(true);
     }
 
     pub fn run(&mut self) {
-        let current_time = self.game_time.current_time();
+        let current_time = match self.next_replay_frame() {
+            Some(frame_duration) => self.game_time.replay_frame(frame_duration),
+            None => self.game_time.current_time(),
+        };
         let last_frame = self.game_time.last_timestamp;
 
+        if let Some(replay) = &mut self.replay {
+            // Overlong levels are left unrecorded, rather than growing the replay without bound.
+            if !replay.record_frame(self.game_time.frame_duration) {
+                log!("Level is too long to be recorded");
+                self.replay = None;
+            }
+        }
+
         self.fps.calc(current_time, last_frame);
 
-        // Game fight
-        BattleManager::manage_fight(self);
+        // Game fight, frozen while paused
+        if !self.game_time.paused {
+            BattleManager::manage_fight(self);
+        }
 
         // Draw game Sprites
         self.draw();
@@ -81,27 +133,34 @@ impl Game {
         self.sprites_garbage_collector();
 
         SunManager::tick(self);
+        WaveManager::tick(self);
+        SurvivalManager::tick(self);
+        RulesManager::tick(self);
+        RechargeManager::tick(self);
+        CombatManager::tick(self);
 
         self.game_time.stamp();
     }
 
     fn draw(&mut self) {
+        let now = self.game_time.time;
+
         self.painter.clear();
 
         self.sprites
             .iter_mut()
             .filter(|sprite| sprite.visible)
             .for_each(|sprite| {
-                // Collect behaviors mutations
-                let mutations = BehaviorManager::run(
-                    sprite,
-                    &self.game_time,
-                    &self.mouse_position,
-                    &self.painter.context,
-                );
+                // Collect behaviors mutations, only the ones running while paused are left during a pause
+                let mutations = BehaviorManager::run(sprite, &self.game_time, &self.mouse_position);
+
+                mutations
+                    .iter()
+                    .filter_map(|mutation| mutation.sound)
+                    .for_each(|sound| self.audio.play_effect(&self.resources, sound));
 
                 // Apply on Sprite
-                sprite.apply_mutation(mutations);
+                sprite.apply_mutation(mutations, now);
 
                 self.painter.draw_sprite(sprite);
             });
@@ -112,21 +171,73 @@ impl Game {
 
     // Canvas Mouse Events //
 
-    pub fn handle_mouse_event(&mut self, event_name: GameMouseEvent, event: MouseEvent) {
-        let current_mouse = Position::from_event(event);
-        self.mouse_position = current_mouse;
+    pub fn handle_mouse_event(&mut self, event_name: GameMouseEvent, position: Position) {
+        if self.replay_player.is_some() {
+            return;
+        }
+
+        self.mouse_position = position;
+
+        // Paused battles only take the clicks declared to run while paused, e.g resuming the battle.
+        // The other events are dropped unrecorded.
+        if self.game_time.paused
+            && !BehaviorManager::hovers_paused_click(&self.sprites, &self.mouse_position)
+        {
+            return;
+        }
+
+        if let Some(replay) = &mut self.replay {
+            replay.record_event(ReplayInput::Mouse(event_name, position));
+        }
 
         match event_name {
             GameMouseEvent::MouseMove => {
                 self.toggle_game_behavior(true, &[BehaviorType::Hover]);
             }
             GameMouseEvent::MouseDown => {
+                // Browsers hold the music back until the player first interacts with the page.
+                self.audio.pause_music(false);
                 self.toggle_game_behavior(true, &[BehaviorType::Click]);
             }
             GameMouseEvent::MouseUp => {
                 self.toggle_game_behavior(false, &[BehaviorType::Click, BehaviorType::Drag]);
             }
             GameMouseEvent::MouseLeave => self.toggle_game_behavior(false, &[BehaviorType::Hover]),
+            GameMouseEvent::MouseCancel => {
+                // Cancelled pointers drop what they drag rather than planting or digging it.
+                BattleScene::cancel_drag(self);
+                self.toggle_game_behavior(false, &[BehaviorType::Click]);
+            }
+        }
+    }
+
+    // Keyboard Events //
+
+    pub fn handle_key_event(&mut self, event: GameKeyEvent) {
+        if self.replay_player.is_some() {
+            return;
+        }
+
+        // Muting is a player setting rather than a battle input, thus it's neither recorded nor paused.
+        if event == GameKeyEvent::ToggleMute {
+            return self.toggle_mute();
+        }
+
+        // Paused battles only take the key resuming them, the other keys are dropped unrecorded.
+        if self.game_time.paused && event != GameKeyEvent::TogglePause {
+            return;
+        }
+
+        if let Some(replay) = &mut self.replay {
+            replay.record_event(ReplayInput::Key(event));
+        }
+
+        match event {
+            GameKeyEvent::PickCard(index) => self.pick_plant_card(index),
+            GameKeyEvent::PickShovel => BattleScene::pick_shovel(self),
+            GameKeyEvent::CancelDrag => BattleScene::cancel_drag(self),
+            GameKeyEvent::TogglePause if GameFeatures::should_spawn_waves() => self.toggle_pause(),
+            GameKeyEvent::TogglePause | GameKeyEvent::ToggleMute => {}
         }
     }
 
@@ -136,6 +247,143 @@ impl Game {
 
     // Game Actions //
 
+    pub fn toggle_pause(&mut self) {
+        let paused = !self.game_time.paused;
+
+        self.game_time.set_paused(paused);
+        self.audio.pause_music(paused);
+
+        // Pausing keeps the battle, so it can be continued after leaving the game.
+        if paused {
+            self.save_battle();
+        }
+
+        BattleScene::toggle_pause_sign(self, paused);
+        BattleScene::update_pause_button(self, paused);
+    }
+
+    /// Captures the running battle, None when there is no battle in progress.
+    pub fn snapshot(&self) -> Option<BattleSnapshot> {
+        BattleSnapshot::capture(self)
+    }
+
+    /// Continues a captured battle, it's replay is not recorded as the battle start is unknown.
+    pub fn restore_snapshot(&mut self, snapshot: &BattleSnapshot) {
+        snapshot.restore(self);
+        BattleScene::update_speed_button(self);
+
+        self.audio.play_music(&self.resources, Music::Battle);
+        self.replay = None;
+        self.last_gc = self.game_time.time;
+    }
+
+    /// Continues the last saved battle, returns whether there was one.
+    pub fn continue_saved_battle(&mut self) -> bool {
+        match BattleSnapshot::load(self.storage.as_ref()) {
+            Some(snapshot) => {
+                self.restore_snapshot(&snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Plays the given replay back, the user inputs are ignored until it's over.
+    pub fn play_replay(&mut self, replay: Replay) {
+        let player = ReplayPlayer::new(replay);
+
+        player.start(self);
+
+        if !player.is_over() {
+            self.replay_player = Some(player);
+        }
+    }
+
+    /// Plays the replay of the last finished level back, returns whether there was one.
+    pub fn play_last_replay(&mut self) -> bool {
+        match Replay::load(self.storage.as_ref()) {
+            Some(replay) => {
+                self.play_replay(replay);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay_player.is_some()
+    }
+
+    /// Handles the replayed events ahead of the upcoming frame, returning the frame recorded duration.
+    fn next_replay_frame(&mut self) -> Option<f64> {
+        let mut player = self.replay_player.take()?;
+        let frame_duration = player.next_frame(self);
+
+        if !player.is_over() {
+            self.replay_player = Some(player);
+        }
+
+        frame_duration
+    }
+
+    fn save_battle(&mut self) {
+        if let Some(snapshot) = self.snapshot() {
+            if let Err(error) = snapshot.store(self.storage.as_mut()) {
+                log!("Cannot save battle: {:?}", error);
+            }
+        }
+    }
+
+    pub fn set_speed(&mut self, speed: f64) {
+        self.game_time.set_speed(speed);
+        self.save_data.settings.speed = self.game_time.speed;
+
+        log!("Game speed: {}x", self.game_time.speed);
+
+        BattleScene::update_speed_button(self);
+        self.save_progress();
+    }
+
+    /// Switches to the next supported game speed, back to the slowest after the fastest.
+    pub fn cycle_speed(&mut self) {
+        // Replays play the recorded frame durations back, leaving the viewer's speed as is.
+        if self.is_replaying() {
+            return;
+        }
+
+        let current = GAME_SPEEDS
+            .iter()
+            .position(|speed| *speed == self.game_time.speed)
+            .unwrap_or_default();
+
+        self.set_speed(GAME_SPEEDS[(current + 1) % GAME_SPEEDS.len()]);
+    }
+
+    /// Sets the audio volume (0 - 1).
+    pub fn set_volume(&mut self, volume: f64) {
+        self.audio.set_volume(volume);
+        self.save_data.settings.volume = self.audio.volume();
+
+        self.save_progress();
+    }
+
+    pub fn toggle_mute(&mut self) {
+        self.audio.set_muted(!self.audio.is_muted());
+        self.save_data.settings.muted = self.audio.is_muted();
+
+        self.save_progress();
+    }
+
+    pub fn play_sound(&mut self, sound: SoundEffect) {
+        self.audio.play_effect(&self.resources, sound);
+    }
+
+    fn save_progress(&mut self) {
+        if let Err(error) = self.save_data.store(self.storage.as_mut()) {
+            log!("Cannot save progress: {:?}", error);
+        }
+    }
+
     pub fn handle_game_interactions(&mut self) {
         let game_interactions = self
             .sprites
@@ -158,7 +406,7 @@ impl Game {
     pub fn interaction_callback(&mut self, callback: &Callback, sprite_id: &String) {
         match callback {
             Callback::ShowZombieHand => self.show_zombie_hand_animation(),
-            Callback::SelectLevel => self.select_level(),
+            Callback::SelectLevel => self.show_level_select(),
             Callback::BackHome => self.start_home_scene(),
             Callback::ShowPlantsChooser => self.show_plants_chooser(),
             Callback::ResetPlantsChoose => self.reset_plants_choose(),
@@ -168,7 +416,7 @@ impl Game {
             Callback::ChooserSeedSelect => self.on_chooser_seed_click(sprite_id),
             Callback::PlantCardClick => self.on_plant_card_click(sprite_id),
             Callback::CollectSun => self.collect_sun(sprite_id),
-            Callback::RemoveSun => self.remove_sprite_by_id(sprite_id),
+            Callback::MissSun => SunManager::miss_sun(self, sprite_id),
             Callback::ReverseSun => self.reverse_sun(sprite_id),
             Callback::Plant => self.plant_on_board(sprite_id),
             Callback::AllowShovelDrag => self.allow_shovel_drag(),
@@ -176,39 +424,154 @@ impl Game {
             Callback::Shoot => self.on_plant_shoot(sprite_id),
             Callback::GenerateSunFlowerSun => self.generate_sunflower_sun(sprite_id),
             Callback::OnZombieDeath => self.on_zombie_death(sprite_id),
+            Callback::ArmorLost => BattleScene::build_armor_drop(self, sprite_id),
             Callback::LawnCleanerLost => self.on_lawn_cleaner_lost(),
+            Callback::ContinueNextLevel => self.continue_next_level(),
+            Callback::StartLevel => self.on_level_button_click(sprite_id),
+            Callback::StartSurvival => self.start_survival(),
+            Callback::ResumeSurvival => SurvivalManager::resume(self),
+            Callback::SelectChallenge => self.show_challenge_select(),
+            Callback::TogglePause => self.toggle_pause(),
+            Callback::CycleSpeed => self.cycle_speed(),
         }
     }
 
     // Scenes //
     pub fn game_over(&mut self, won: bool) {
+        GameFeatures::enable_spawn_waves(false);
         GameFeatures::enable_generate_sun(false);
         GameFeatures::enable_update_sun_score(false);
+        RulesManager::end_battle(self);
+
+        // The level is kept to be played back, e.g when reporting a bug.
+        if let Some(replay) = &self.replay {
+            if let Err(error) = replay.store(self.storage.as_mut()) {
+                log!("Cannot store level replay: {:?}", error);
+            }
+        }
+
+        BattleSnapshot::clear(self.storage.as_mut());
+        self.audio.stop_music();
 
         if !won {
+            if self.state.survival.is_some() {
+                SurvivalManager::finish(self);
+                self.save_progress();
+            }
+
             self.toggle_game_behavior(false, &[BehaviorType::Walk, BehaviorType::Animate]);
             return BattleScene::zombies_won(self);
         }
 
-        // TODO Missing GameWon Scene
-        log!("Game won ! muhahahaha")
+        // Challenges are all open from the start, they take no part in the campaign progress.
+        let level = self.state.get_level();
+        if !ChallengeSelect::is_challenge(self, &level.name) {
+            self.save_data.complete_level(&level);
+            self.save_progress();
+        }
+
+        // Freeze the board, leaving the victory overlay as the only way forward.
+        self.toggle_game_behavior(false, &[BehaviorType::Interval, BehaviorType::Collision]);
+
+        BattleScene::level_won(self);
+    }
+
+    pub fn continue_next_level(&mut self) {
+        let level_id = self.state.get_level().name;
+
+        if ChallengeSelect::is_challenge(self, &level_id) {
+            self.start_home_scene();
+            return self.show_challenge_select();
+        }
+
+        match self.resources.next_level_id(&level_id) {
+            Some(next_level_id) => self.select_level(&next_level_id),
+            None => self.start_home_scene(),
+        }
     }
 
     fn start_home_scene(&mut self) {
         self.toggle_game_behavior(false, &[BehaviorType::Collision]);
 
         self.reset_state();
+        self.replay = None;
 
         GameFeatures::enable_update_sun_score(false);
         GameFeatures::enable_generate_sun(false);
+        GameFeatures::enable_spawn_waves(false);
 
+        self.audio.play_music(&self.resources, Music::Home);
         HomeScene::start(self);
     }
 
-    fn select_level(&mut self) {
+    fn show_level_select(&mut self) {
+        self.state.level_buttons = vec![];
+
+        LevelSelect::show(self);
+    }
+
+    fn show_challenge_select(&mut self) {
+        self.state.level_buttons = vec![];
+
+        ChallengeSelect::show(self);
+    }
+
+    fn on_level_button_click(&mut self, sprite_id: &String) {
+        let level_id = self
+            .state
+            .level_buttons
+            .iter()
+            .find(|(button_id, _level_id)| button_id == sprite_id)
+            .map(|(_button_id, level_id)| level_id.clone());
+
+        if let Some(level_id) = level_id {
+            if LevelSelect::is_unlocked(self, &level_id)
+                || ChallengeSelect::is_challenge(self, &level_id)
+            {
+                self.select_level(&level_id);
+            }
+        }
+    }
+
+    fn select_level(&mut self, level_id: &str) {
+        let seed = self.level_seed.unwrap_or_else(|| self.random.next_u64());
+
+        self.start_level_by_id(level_id, seed);
+    }
+
+    fn start_survival(&mut self) {
+        self.select_level(SURVIVAL_LEVEL);
+    }
+
+    /// Starts the level by it's id, where the survival level starts an endless survival run.
+    pub fn start_level_by_id(&mut self, level_id: &str, seed: u64) {
+        match level_id {
+            SURVIVAL_LEVEL => SurvivalManager::start(self, seed),
+            _ => self.start_level(self.resources.get_level_data(level_id), seed),
+        }
+    }
+
+    pub fn start_level(&mut self, level: LevelData, seed: u64) {
         self.reset_state();
 
-        self.state.current_level = Some(self.resources.get_level_data("1-1"));
+        // Each level runs over it's own seeded randomness, so it can be reproduced by seed.
+        self.random = Random::new(seed);
+        self.state.seed = seed;
+
+        log!("Starting level {} with seed: {}", level.name, seed);
+
+        // Record the level from it's very start, aligning the garbage collector with it as well.
+        self.replay = Some(Replay::new(
+            &level.name,
+            seed,
+            self.game_time.time,
+            self.mouse_position,
+        ));
+        self.last_gc = self.game_time.time;
+
+        self.state.current_level = Some(level);
+
+        SunManager::start_level(self);
 
         GameFeatures::enable_board_lines(true);
 
@@ -222,6 +585,12 @@ impl Game {
     pub fn show_plants_chooser(&mut self) {
         GameFeatures::enable_update_sun_score(true);
 
+        if !self.state.get_level().rules.choose_plants {
+            RulesManager::give_plant_cards(self);
+            return self.enter_battle_animation();
+        }
+
+        self.audio.play_music(&self.resources, Music::Chooser);
         PlantsChooser::show(self);
     }
 
@@ -236,11 +605,16 @@ impl Game {
     }
 
     pub fn start_battle_callout(&mut self) {
+        self.play_sound(SoundEffect::ReadySetPlant);
         BattleScene::battle_callout(self);
     }
 
     pub fn start_battle(&mut self) {
-        GameFeatures::enable_generate_sun(true);
+        GameFeatures::enable_spawn_waves(true);
+        SunManager::start_battle(self);
+        RulesManager::start_battle(self);
+
+        self.audio.play_music(&self.resources, Music::Battle);
         BattleScene::start(self);
     }
 
@@ -276,13 +650,34 @@ impl Game {
     }
 
     pub fn on_plant_card_click(&mut self, sprite_id: &String) {
-        let sun_cost = self.get_sprite_by_id(sprite_id).sun_cost;
-
-        if self.state.sun_state.score >= sun_cost as i32 {
+        if self.can_afford_card(sprite_id) && RechargeManager::is_ready(self, sprite_id) {
             BattleScene::create_draggable_plant(self, sprite_id);
         }
     }
 
+    fn pick_plant_card(&mut self, card_index: usize) {
+        let card_id = match self.state.selected_seeds.get(card_index) {
+            Some((_seed_id, card_id)) => card_id.clone(),
+            None => return,
+        };
+
+        if BattleScene::is_plant_card_active(self, &card_id)
+            && self.can_afford_card(&card_id)
+            && RechargeManager::is_ready(self, &card_id)
+        {
+            BattleScene::cancel_drag(self);
+
+            let plant_id = BattleScene::create_draggable_plant(self, &card_id);
+            BattleScene::attach_to_mouse(self, &plant_id);
+        }
+    }
+
+    fn can_afford_card(&mut self, card_id: &String) -> bool {
+        let sun_cost = self.get_sprite_by_id(card_id).sun_cost;
+
+        self.state.sun_state.score >= sun_cost as i32
+    }
+
     pub fn allow_shovel_drag(&mut self) {
         BattleScene::allow_shovel_drag(self);
     }
@@ -321,9 +716,17 @@ impl Game {
 
         if self.is_free_board_location(sprite_id, &target_location) {
             let cost = self.get_sprite_by_id(sprite_id).sun_cost;
+            let source_card = self.get_sprite_by_id(sprite_id).source_card.clone();
 
             SunManager::change_score(self, -(cost as i32));
             BattleScene::create_plant(self, sprite_id);
+
+            if let Some(card_id) = source_card {
+                RechargeManager::on_plant(self, &card_id);
+                RulesManager::on_plant(self, &card_id);
+            }
+
+            self.play_sound(SoundEffect::Plant);
         } else {
             self.remove_sprite_by_id(sprite_id)
         }
@@ -332,18 +735,12 @@ impl Game {
     }
 
     pub fn on_plant_shoot(&mut self, sprite_id: &String) {
-        let shooting_plant_location = &self.get_sprite_by_id(sprite_id).board_location.clone();
-
-        // Check if row contains an enemy
-        let has_enemy_in_row = self.has_enemy_in_row(shooting_plant_location);
-
-        if has_enemy_in_row.is_some() {
-            BattleScene::create_bullet(self, sprite_id)
-        }
+        CombatManager::shoot(self, sprite_id);
     }
 
     pub fn collect_sun(&mut self, sprite_id: &String) {
         SunManager::collect_sun(self, sprite_id);
+        self.play_sound(SoundEffect::CollectSun);
         BattleScene::toggle_cards_grayscale(self);
     }
 
@@ -358,8 +755,15 @@ impl Game {
     }
 
     pub fn on_zombie_death(&mut self, zombie_id: &String) {
-        if !self.has_remaining_zombies() {
-            self.game_over(true);
+        self.state.wave_state.killed_zombies += 1;
+        self.play_sound(SoundEffect::ZombieDeath);
+        BattleScene::update_flag_meter(self);
+
+        if !self.has_remaining_zombies() && WaveManager::all_waves_spawned(self) {
+            match self.state.survival.is_some() {
+                true => SurvivalManager::complete_round(self),
+                false => self.game_over(true),
+            }
         }
 
         // Builds Zombie head animation
@@ -368,6 +772,7 @@ impl Game {
 
     pub fn on_lawn_cleaner_lost(&mut self) {
         self.state.lost_lawn_cleaners += 1;
+        self.play_sound(SoundEffect::LawnMower);
 
         if self.state.lost_lawn_cleaners == MAX_LAWN_CLEANERS_LOST {
             self.game_over(false);
@@ -427,7 +832,9 @@ impl Game {
     }
 
     fn sort_sprites(&mut self) {
-        self.sprites.sort_by(|a, b| a.order.cmp(&b.order));
+        // Plants sharing a cell are drawn from their bottom layer up.
+        self.sprites
+            .sort_by_key(|sprite| (sprite.order, sprite.layer));
     }
 
     // Getters //
@@ -446,57 +853,46 @@ impl Game {
         self.sprites
             .iter_mut()
             .find(|sprite| name == sprite.name && &sprite.sprite_type == sprite_type)
-            .unwrap_or_else(|| panic!("[Game Controller] Cannot find Sprite {}", &name))
+            .unwrap_or_else(|| panic!("[Game Controller] Cannot find Sprite {}", name))
     }
 
     pub fn get_sprite_by_id(&mut self, sprite_id: &String) -> &mut Sprite {
         self.sprites
             .iter_mut()
             .find(|sprite| sprite_id == &sprite.id)
-            .unwrap_or_else(|| panic!("[Game Controller] Cannot find Sprite {}", &sprite_id))
+            .unwrap_or_else(|| panic!("[Game Controller] Cannot find Sprite {}", sprite_id))
     }
 
+    /// The top layer plant of the given board cell.
     pub fn get_sprite_by_location(&mut self, location: &BoardLocation) -> Option<&Sprite> {
-        let found = self
-            .sprites
+        self.get_plants_by_location(location)
+            .into_iter()
+            .max_by_key(|sprite| sprite.layer)
+    }
+
+    fn get_plants_by_location(&self, location: &BoardLocation) -> Vec<&Sprite> {
+        self.sprites
             .iter()
             .filter(|sprite| sprite.sprite_type == SpriteType::Plant)
-            .find(|sprite| {
+            .filter(|sprite| {
                 location.row == sprite.board_location.row && location.col == sprite.board_location.col
-            });
-
-        found
+            })
+            .collect()
     }
 
+    /// Whether the plant layer is free at the given board cell, where ground plants require an empty cell.
     pub fn is_free_board_location(&mut self, sprite_id: &String, location: &BoardLocation) -> bool {
-        let found = self.get_sprite_by_location(location);
+        let layer = self.get_sprite_by_id(sprite_id).layer;
 
-        match found {
-            None => true,
-            Some(sprite) if &sprite.id == sprite_id => true,
-            Some(_) => false,
-        }
+        self.get_plants_by_location(location)
+            .iter()
+            .filter(|sprite| &sprite.id != sprite_id)
+            .all(|sprite| layer != PlantLayer::Ground && sprite.layer != layer)
     }
 
     fn has_remaining_zombies(&mut self) -> bool {
-        !self
-            .get_sprites_by_type(&SpriteType::Zombie)
+        self.get_sprites_by_type(&SpriteType::Zombie)
             .iter()
-            .filter(|zombie| !zombie.attack_state.is_dead())
-            .next()
-            .is_none()
-    }
-
-    fn has_enemy_in_row(&mut self, shooting_plant_location: &BoardLocation) -> Option<&mut Sprite> {
-        self.sprites.iter_mut().find(|sprite| {
-            sprite.visible
-                && !sprite.attack_state.is_dead()
-                && sprite.sprite_type == SpriteType::Zombie
-                && sprite.board_location.row == shooting_plant_location.row
-        })
-    }
-
-    pub fn canvas(&self) -> &HtmlCanvasElement {
-        &self.painter.canvas
+            .any(|zombie| !zombie.attack_state.is_dead())
     }
 }
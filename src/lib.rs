@@ -2,8 +2,10 @@ use engine::Engine;
 use wasm_bindgen::prelude::*;
 use web_utils::bind_panic_logger;
 
+mod audio;
 mod battle_manage;
 mod board;
+mod combat_manager;
 mod constants;
 mod engine;
 mod features;
@@ -12,11 +14,20 @@ mod game;
 mod location_builder;
 mod model;
 mod painter;
+mod random;
+mod recharge_manager;
+mod replay;
 mod resource_loader;
+mod rules_manager;
 mod scene;
+pub mod simulation;
+mod snapshot;
 mod sprite;
+mod storage;
 mod sun_manager;
+mod survival_manager;
 mod timers;
+mod wave_manager;
 mod web_utils;
 
 #[wasm_bindgen(start)]
@@ -1,7 +1,6 @@
-use js_sys::Math;
-
-use crate::board::Board;
+use crate::board::{Board, ROW_Y_COORD};
 use crate::model::{LocationType, Position, Size, SpriteCell};
+use crate::random::Random;
 use crate::sprite::Sprite;
 
 pub struct LocationBuilder;
@@ -20,30 +19,26 @@ impl LocationBuilder {
         }
     }
 
-    pub fn sun_location() -> Position {
-        Position::new(
-            Self::rand_within_rand(0.0, 80.0),
-            Self::rand_within_rand(100.0, 750.0),
-        )
+    pub fn sun_location(random: &mut Random) -> Position {
+        Position::new(random.range(0.0, 80.0), random.range(100.0, 750.0))
     }
 
-    pub fn plant_location(plant_cell: &SpriteCell, mouse: &Position) -> Position {
-        let location = Board::get_board_location(mouse);
-
-        Board::get_board_placement(plant_cell, location.row, location.col)
-    }
+    /// Places a bullet by it's shooting plant, shifted into the lane row when it differs from the plant row.
+    pub fn bullet_location(plant_position: &Position, plant_row: usize, lane_row: usize) -> Position {
+        let row_shift = ROW_Y_COORD[lane_row] - ROW_Y_COORD[plant_row];
 
-    pub fn bullet_location(plant_position: &Position) -> Position {
-        Position::new(plant_position.top + 6.0, plant_position.left + 20.0)
+        Position::new(
+            plant_position.top + 6.0 + row_shift,
+            plant_position.left + 20.0,
+        )
     }
 
-    pub fn zombie_location(zombie_cell: &SpriteCell, row: usize) -> Position {
+    pub fn zombie_location(zombie_cell: &SpriteCell, row: usize, random: &mut Random) -> Position {
         let start_col = 10;
-        let start_row = ((row) % 5) + 1;
 
-        let board_position = Board::get_board_placement(zombie_cell, start_row, start_col);
+        let board_position = Board::get_board_placement(zombie_cell, row, start_col);
 
-        let x_offset = Self::random_offset(0, 40);
+        let x_offset = Self::random_offset(0, 40, random);
 
         Position::new(board_position.top, board_position.left + x_offset)
     }
@@ -69,17 +64,10 @@ impl LocationBuilder {
         )
     }
 
-    fn rand_within_rand(min: f64, max: f64) -> f64 {
-        let min = Math::ceil(min);
-        let max = Math::floor(max);
-
-        Math::floor(Math::random() * (max - min + 1.0)) + min
-    }
-
-    fn random_offset(min: usize, max: usize) -> f64 {
-        let x_adjustment = Self::rand_within_rand(min as f64, max as f64);
+    fn random_offset(min: usize, max: usize, random: &mut Random) -> f64 {
+        let x_adjustment = random.range(min as f64, max as f64);
 
-        let direction = match Self::rand_within_rand(0.0, 1.0) > 0.5 {
+        let direction = match random.range(0.0, 1.0) > 0.5 {
             true => 1.0,
             false => -1.0,
         };
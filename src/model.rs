@@ -1,10 +1,15 @@
 use std::fmt;
 
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 use web_sys::{MouseEvent, TextMetrics};
 
+use crate::combat_manager::CombatState;
+use crate::recharge_manager::RechargeState;
 use crate::resource_loader::ResourceKind;
+use crate::rules_manager::ConveyorState;
 use crate::sun_manager::SunState;
+use crate::survival_manager::SurvivalState;
+use crate::wave_manager::WaveState;
 
 pub type SelectedSeed = (String, String);
 pub type Dimensions = SpriteCell;
@@ -12,18 +17,37 @@ pub type Dimensions = SpriteCell;
 #[derive(Debug, Default)]
 pub struct GameState {
     pub sun_state: SunState,
+    pub wave_state: WaveState,
     pub current_level: Option<LevelData>,
     pub selected_seeds: Vec<SelectedSeed>,
+    /// Level select buttons, each represented as a Sprite id / Level id tuple.
+    pub level_buttons: Vec<(String, String)>,
     pub lost_lawn_cleaners: usize,
+    /// The random seed the current level was started with.
+    pub seed: u64,
+    /// Set while playing the endless survival mode.
+    pub survival: Option<SurvivalState>,
+    pub conveyor_state: ConveyorState,
+    pub recharge_state: RechargeState,
+    pub combat_state: CombatState,
+    pub battle_stats: BattleStats,
 }
 
 impl GameState {
     pub fn new() -> GameState {
         GameState {
             sun_state: SunState::new(),
+            wave_state: WaveState::new(),
             current_level: None,
             selected_seeds: vec![],
+            level_buttons: vec![],
             lost_lawn_cleaners: 0,
+            seed: 0,
+            survival: None,
+            conveyor_state: ConveyorState::new(),
+            recharge_state: RechargeState::new(),
+            combat_state: CombatState::new(),
+            battle_stats: BattleStats::default(),
         }
     }
 
@@ -35,13 +59,39 @@ impl GameState {
     }
 }
 
+/// Statistics of the current battle.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BattleStats {
+    pub collected_sun: usize,
+    /// Sun gone uncollected.
+    pub missed_sun: usize,
+}
+
 /// The HTML Canvas events being listened by our game.
-#[derive(Debug, Clone, Copy)]
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum GameMouseEvent {
     MouseDown,
     MouseMove,
     MouseUp,
     MouseLeave,
+    /// The pointer was taken over by the browser, e.g a touch turned into scrolling.
+    MouseCancel,
+}
+
+impl GameMouseEvent {
+    /// Maps a DOM pointer event type into a game mouse event.
+    /// Pointer events unify mouse, pen and touch input.
+    pub fn from_pointer_event(event_type: &str) -> Option<GameMouseEvent> {
+        match event_type {
+            "pointerdown" => Some(GameMouseEvent::MouseDown),
+            "pointermove" => Some(GameMouseEvent::MouseMove),
+            "pointerup" => Some(GameMouseEvent::MouseUp),
+            "pointercancel" => Some(GameMouseEvent::MouseCancel),
+            "pointerleave" => Some(GameMouseEvent::MouseLeave),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for GameMouseEvent {
@@ -50,8 +100,36 @@ impl fmt::Display for GameMouseEvent {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+/// The keyboard shortcuts handled by our game.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GameKeyEvent {
+    /// Picks the plant card at the given index of the selected seeds.
+    PickCard(usize),
+    PickShovel,
+    CancelDrag,
+    TogglePause,
+    ToggleMute,
+}
+
+impl GameKeyEvent {
+    /// Maps a browser `KeyboardEvent.key` value into a game key event.
+    pub fn from_key(key: &str) -> Option<GameKeyEvent> {
+        match key {
+            "Escape" => Some(GameKeyEvent::CancelDrag),
+            "s" | "S" => Some(GameKeyEvent::PickShovel),
+            "p" | "P" => Some(GameKeyEvent::TogglePause),
+            "m" | "M" => Some(GameKeyEvent::ToggleMute),
+            _ => match key.parse::<usize>() {
+                Ok(number) if number > 0 => Some(GameKeyEvent::PickCard(number - 1)),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
 pub enum Callback {
+    #[default]
     ShowZombieHand,
     SelectLevel,
     BackHome,
@@ -62,7 +140,7 @@ pub enum Callback {
     StartBattle,
     ChooserSeedSelect,
     PlantCardClick,
-    RemoveSun,
+    MissSun,
     CollectSun,
     ReverseSun,
     Plant,
@@ -71,12 +149,70 @@ pub enum Callback {
     Shoot,
     GenerateSunFlowerSun,
     OnZombieDeath,
+    ArmorLost,
     LawnCleanerLost,
+    ContinueNextLevel,
+    StartLevel,
+    StartSurvival,
+    ResumeSurvival,
+    SelectChallenge,
+    TogglePause,
+    CycleSpeed,
+}
+
+/// Sound effects, each played by it's name within the sound data.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SoundEffect {
+    Shoot,
+    CollectSun,
+    Plant,
+    ZombieDeath,
+    LawnMower,
+    Chomp,
+    ReadySetPlant,
+}
+
+impl SoundEffect {
+    pub const ALL: [SoundEffect; 7] = [
+        SoundEffect::Shoot,
+        SoundEffect::CollectSun,
+        SoundEffect::Plant,
+        SoundEffect::ZombieDeath,
+        SoundEffect::LawnMower,
+        SoundEffect::Chomp,
+        SoundEffect::ReadySetPlant,
+    ];
+
+    pub fn value(&self) -> &str {
+        match *self {
+            SoundEffect::Shoot => "Shoot",
+            SoundEffect::CollectSun => "CollectSun",
+            SoundEffect::Plant => "Plant",
+            SoundEffect::ZombieDeath => "ZombieDeath",
+            SoundEffect::LawnMower => "LawnMower",
+            SoundEffect::Chomp => "Chomp",
+            SoundEffect::ReadySetPlant => "ReadySetPlant",
+        }
+    }
+}
+
+/// Looping scene music, played by it's name within the sound data.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Music {
+    Home,
+    Chooser,
+    Battle,
 }
 
-impl Default for Callback {
-    fn default() -> Callback {
-        Callback::ShowZombieHand
+impl Music {
+    pub const ALL: [Music; 3] = [Music::Home, Music::Chooser, Music::Battle];
+
+    pub fn value(&self) -> &str {
+        match *self {
+            Music::Home => "HomeMusic",
+            Music::Chooser => "ChooserMusic",
+            Music::Battle => "BattleMusic",
+        }
     }
 }
 
@@ -88,30 +224,7 @@ pub enum GameInteraction {
     AnimationCallback(Callback, SpriteId),
 }
 
-#[derive(Debug)]
-pub enum Plant {
-    PeaShooter,
-    SnowPea,
-}
-
-impl Plant {
-    pub fn from_name(name: &str) -> Plant {
-        match name {
-            "PeaShooter" => Plant::PeaShooter,
-            "SnowPea" => Plant::SnowPea,
-            _ => Plant::PeaShooter,
-        }
-    }
-
-    pub fn bullet_type(plant: &Plant) -> &str {
-        match plant {
-            Plant::PeaShooter => "NormalBullet",
-            Plant::SnowPea => "SnowBullet",
-        }
-    }
-}
-
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum SpriteType {
     Zombie,
     Plant,
@@ -130,7 +243,7 @@ impl SpriteType {
             ResourceKind::Interface => SpriteType::Interface,
             ResourceKind::Plant => SpriteType::Plant,
             ResourceKind::Zombie => SpriteType::Zombie,
-            ResourceKind::Level => SpriteType::Meta,
+            ResourceKind::Level | ResourceKind::Campaign | ResourceKind::Sound => SpriteType::Meta,
         }
     }
 }
@@ -144,9 +257,81 @@ pub struct SpriteCell {
     pub height: f64,
 }
 
-#[derive(Debug, Copy, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AttackEffect {
     TurnIntoFireBullet,
+    /// Chills the target, slowing it down for a while.
+    Slow,
+    /// Fire warms the target up, cancelling any `Slow` effect.
+    Thaw,
+}
+
+/// The path of an attack, telling which armor it's blocked by.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq)]
+pub enum Trajectory {
+    #[default]
+    Straight,
+    /// Lobbed over shields, yet still landing on helmets.
+    Lobbed,
+}
+
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ArmorKind {
+    /// Worn on the head, blocking any attack.
+    #[default]
+    Helmet,
+    /// Held ahead of the zombie, blocking straight attacks only.
+    Shield,
+}
+
+impl ArmorKind {
+    pub fn blocks(&self, trajectory: Trajectory) -> bool {
+        match self {
+            ArmorKind::Helmet => true,
+            ArmorKind::Shield => trajectory == Trajectory::Straight,
+        }
+    }
+}
+
+/// Armor of a zombie, taking the blocked damage ahead of the zombie itself.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ArmorData {
+    pub kind: ArmorKind,
+    pub life: f64,
+    /// The swap cells index the zombie walks by once it's armor breaks, followed by it's attacking cells.
+    pub swap: usize,
+    /// The Sprite of the armor falling off once it breaks.
+    pub drop: Option<String>,
+}
+
+impl Default for ArmorData {
+    fn default() -> Self {
+        ArmorData {
+            kind: ArmorKind::Helmet,
+            life: 100.0,
+            swap: 2,
+            drop: None,
+        }
+    }
+}
+
+/// Special movements of zombies, each taken once along the battle.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MovementKind {
+    /// Leaps over the first plant it reaches.
+    Vault,
+    /// Leaps into a neighbour row upon the first plant it reaches.
+    SwitchRow,
+    /// Tunnels under the board right away, surfacing at it's back column to walk the row the other way.
+    Dig,
+}
+
+impl MovementKind {
+    /// Whether the movement is taken upon reaching a plant, instead of eating it.
+    pub fn on_contact(&self) -> bool {
+        matches!(self, MovementKind::Vault | MovementKind::SwitchRow)
+    }
 }
 
 /// Sprite data represents the meta data of a given Sprite
@@ -161,10 +346,27 @@ pub struct SpriteData {
     pub damage: f64,
     pub attack_effect: Option<AttackEffect>,
     pub sun_cost: usize,
+    /// Time (ms) a battle card takes to recharge once it's plant is placed.
+    pub recharge: f64,
     pub draw_offset: Position,
+    /// Draws the cells of another Sprite by it's name, e.g a scaled variant of the same art.
+    pub cell: Option<String>,
     pub swap_cells: Vec<String>,
     pub behaviors: Vec<BehaviorData>,
     pub text_overlay: Option<TextOverlayData>,
+    /// Shooting attributes, for plants which shoot.
+    pub combat: Option<CombatData>,
+    /// The board cell layer taken by a plant.
+    pub layer: PlantLayer,
+    /// Eating attributes, for zombies which eat plants.
+    pub eating: Option<EatingData>,
+    /// Remaining life parts (0 - 1) below which the Sprite swaps into it's next swap cell, from the top down.
+    pub damage_stages: Vec<f64>,
+    pub armor: Option<ArmorData>,
+    /// The path of the Sprite attacks, e.g a bullet lobbed over shields.
+    pub trajectory: Trajectory,
+    /// Special movement of a zombie, taken by it's Leap behavior and drawn by the swap cells following it's dying ones.
+    pub movement: Option<MovementKind>,
 }
 
 impl Default for SpriteData {
@@ -178,16 +380,138 @@ impl Default for SpriteData {
             damage: 0.0,
             attack_effect: None,
             sun_cost: 0,
+            recharge: 0.0,
             exact_outlines: false,
             behaviors: vec![],
+            cell: None,
             swap_cells: vec![],
             text_overlay: None,
+            combat: None,
+            layer: PlantLayer::Main,
+            eating: None,
+            damage_stages: vec![],
+            armor: None,
+            trajectory: Trajectory::Straight,
+            movement: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+/// Layers a board cell is stacked of, from the bottom up. A cell holds a single plant of each layer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PlantLayer {
+    /// Plants other plants are placed upon, thus only placed into an empty cell.
+    Ground,
+    #[default]
+    Main,
+    /// Plants wrapping the plant of their cell, taking the zombies bites first.
+    Shell,
+}
+
+/// Eating attributes of a zombie, whose damage is dealt bite by bite.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct EatingData {
+    /// Damage dealt per second of eating.
+    pub dps: f64,
+    /// Time (ms) between bites.
+    pub bite_rate: f64,
+}
+
+impl EatingData {
+    pub fn bite_damage(&self) -> f64 {
+        self.dps * self.bite_rate / 1000.0
+    }
+}
+
+impl Default for EatingData {
+    fn default() -> Self {
+        EatingData {
+            dps: 5.0,
+            bite_rate: 1000.0,
+        }
+    }
+}
+
+/// Shooting attributes of a plant, where each shot fires along all of the plant lanes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CombatData {
+    /// The bullet Sprite being shot.
+    pub bullet: String,
+    /// Time (ms) between shots.
+    pub fire_rate: f64,
+    /// Board columns a zombie has to be within in order to be shot at, 0 for the whole row.
+    pub range: usize,
+    /// Shoots only at zombies ahead of the plant in the lane direction, rather than anywhere in the row.
+    pub only_ahead: bool,
+    /// Delay (ms) between the projectiles of a single shot.
+    pub stagger: f64,
+    pub lanes: Vec<LaneData>,
+}
+
+impl Default for CombatData {
+    fn default() -> Self {
+        CombatData {
+            bullet: String::from("NormalBullet"),
+            fire_rate: 3000.0,
+            range: 0,
+            only_ahead: true,
+            stagger: 150.0,
+            lanes: vec![LaneData::default()],
+        }
+    }
+}
+
+/// A lane a plant shoots along, each lane fires once it has a zombie to shoot at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaneData {
+    pub direction: ShootDirection,
+    /// The lane row, relative to the plant row.
+    pub row_offset: i32,
+    /// Projectiles fired per shot.
+    pub projectiles: usize,
+}
+
+impl Default for LaneData {
+    fn default() -> Self {
+        LaneData {
+            direction: ShootDirection::Forward,
+            row_offset: 0,
+            projectiles: 1,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ShootDirection {
+    #[default]
+    Forward,
+    Back,
+}
+
+/// Sound data points a named sound into it's audio file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SoundData {
+    pub src: String,
+    /// Relative to the player volume setting (0 - 1).
+    pub volume: f64,
+}
+
+impl Default for SoundData {
+    fn default() -> Self {
+        Self {
+            src: String::new(),
+            volume: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum BehaviorType {
+    #[default]
     Hover,
     Click,
     Animate,
@@ -196,12 +520,8 @@ pub enum BehaviorType {
     Drag,
     Interval,
     Collision,
-}
-
-impl Default for BehaviorType {
-    fn default() -> BehaviorType {
-        BehaviorType::Hover
-    }
+    Fade,
+    Leap,
 }
 
 impl BehaviorType {
@@ -215,18 +535,20 @@ impl BehaviorType {
             "Drag" => BehaviorType::Drag,
             "Interval" => BehaviorType::Interval,
             "Collision" => BehaviorType::Collision,
+            "Fade" => BehaviorType::Fade,
+            "Leap" => BehaviorType::Leap,
             _ => BehaviorType::default(),
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Velocity {
     pub x: f64,
     pub y: f64,
 }
 
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
 pub struct CollisionMargin {
     pub left: usize,
     pub right: usize,
@@ -245,7 +567,10 @@ pub struct BehaviorData {
     pub max_cycles: Option<usize>,
     pub velocity: Option<Velocity>,
     pub interval: Option<f64>,
+    pub duration: Option<f64>,
     pub collision_margin: Option<CollisionMargin>,
+    /// Clicks taken while the game is paused, e.g resuming it.
+    pub while_paused: bool,
 }
 
 impl BehaviorData {
@@ -258,18 +583,13 @@ impl BehaviorData {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
 pub enum LocationType {
+    #[default]
     Center,
     Top,
 }
 
-impl Default for LocationType {
-    fn default() -> Self {
-        LocationType::Center
-    }
-}
-
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(default)]
 pub struct TextOverlayData {
@@ -280,7 +600,7 @@ pub struct TextOverlayData {
     pub color: Option<String>,
 }
 
-#[derive(Debug, Default, PartialEq, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub struct Position {
     pub left: f64,
     pub top: f64,
@@ -291,7 +611,7 @@ impl Position {
         Self { top, left }
     }
 
-    pub fn from_event(event: MouseEvent) -> Self {
+    pub fn from_event(event: &MouseEvent) -> Self {
         Self {
             top: event.offset_y() as f64,
             left: event.offset_x() as f64,
@@ -329,12 +649,17 @@ impl From<TextMetrics> for Size {
     }
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct LevelData {
     pub name: String,
+    /// The name presented to the player, otherwise the level id is presented.
+    #[serde(default)]
+    pub title: Option<String>,
     pub flag_num: usize,
     pub plant_cards: Vec<String>,
-    pub zombies: Vec<String>,
+    pub waves: Vec<WaveData>,
+    #[serde(default)]
+    pub rules: LevelRules,
 }
 
 impl LevelData {
@@ -344,3 +669,149 @@ impl LevelData {
         }
     }
 }
+
+/// Rules a level may override, allowing variants of the battle (e.g challenges) to be declared in data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LevelRules {
+    /// Plants are chosen ahead of the battle, otherwise the level plant cards are given as is.
+    pub choose_plants: bool,
+    /// Plants placed upon the board once the battle starts.
+    pub planted: Vec<PlantPlacement>,
+    /// Delivers single use cards along the battle.
+    pub conveyor: Option<ConveyorData>,
+    /// The sun economy, e.g night levels where no sun falls from the sky.
+    pub sun: SunData,
+}
+
+impl Default for LevelRules {
+    fn default() -> Self {
+        LevelRules {
+            choose_plants: true,
+            planted: vec![],
+            conveyor: None,
+            sun: SunData::default(),
+        }
+    }
+}
+
+/// The sun economy of a level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SunData {
+    /// The sun score the level starts with.
+    pub starting: i32,
+    /// Time (ms) between sun drops from the sky.
+    pub interval: f64,
+    /// The value of a regular sun.
+    pub value: i32,
+    /// Time (ms) a landed sun lasts uncollected, fading away along it's end.
+    pub lifetime: f64,
+    /// No sun falls from the sky at night, leaving Sunflowers as the only source of sun.
+    pub night: bool,
+    pub sky_sun: SunSize,
+    pub sunflower_sun: SunSize,
+    /// The velocity Sunflower sun pops out at, before falling down next to it's Sunflower.
+    pub sunflower_velocity: Velocity,
+}
+
+impl Default for SunData {
+    fn default() -> Self {
+        SunData {
+            starting: 275,
+            interval: 15.0 * 1000.0,
+            value: 25,
+            lifetime: 9.0 * 1000.0,
+            night: false,
+            sky_sun: SunSize::Normal,
+            sunflower_sun: SunSize::Normal,
+            sunflower_velocity: Velocity { x: 30.0, y: -75.0 },
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SunSize {
+    Small,
+    #[default]
+    Normal,
+    Big,
+}
+
+impl SunSize {
+    pub const ALL: [SunSize; 3] = [SunSize::Small, SunSize::Normal, SunSize::Big];
+
+    /// The scale a sun of this size is drawn at.
+    pub fn scale(&self) -> f64 {
+        match *self {
+            SunSize::Small => 0.6,
+            SunSize::Normal => 1.0,
+            SunSize::Big => 1.3,
+        }
+    }
+
+    pub fn from_scale(scale: f64) -> Option<SunSize> {
+        Self::ALL.into_iter().find(|size| size.scale() == scale)
+    }
+
+    /// The sun score a sun of this size is worth, where a small sun is worth 3/5 of a regular one
+    /// and a big sun is worth twice as much.
+    pub fn sun_value(&self, sun: &SunData) -> i32 {
+        match *self {
+            SunSize::Small => sun.value * 3 / 5,
+            SunSize::Normal => sun.value,
+            SunSize::Big => sun.value * 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlantPlacement {
+    pub name: String,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A conveyor belt delivers free plant cards, each gone once it's plant is planted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConveyorData {
+    /// Time (ms) between card deliveries.
+    pub interval: f64,
+    /// Plants the delivered cards are randomly picked from.
+    pub plants: Vec<String>,
+    /// The most cards held at once, deliveries are skipped while the belt is full.
+    pub capacity: usize,
+}
+
+/// A group of zombies spawned together during a battle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WaveData {
+    /// Time (ms) to wait since the previous wave was spawned, or since the battle started.
+    pub delay: f64,
+    pub zombies: Vec<String>,
+    /// Pins the wave zombies into a single board row (1 - 5), otherwise each zombie picks a random one.
+    #[serde(default, deserialize_with = "deserialize_wave_row")]
+    pub row: Option<usize>,
+    /// Flag waves are the level "huge" waves, lead by a Flag zombie.
+    #[serde(default)]
+    pub flag: bool,
+    /// Life added to each of the wave zombies, toughening them beyond their data.
+    #[serde(default)]
+    pub extra_life: f64,
+}
+
+/// Rejects wave rows off the board, failing the level data load rather than the spawn.
+fn deserialize_wave_row<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let row = <Option<usize> as serde::Deserialize>::deserialize(deserializer)?;
+
+    match row {
+        Some(row) if !(1..=5).contains(&row) => Err(serde::de::Error::custom(format!(
+            "wave row {} is off the board (1 - 5)",
+            row
+        ))),
+        _ => Ok(row),
+    }
+}
@@ -3,10 +3,37 @@ use std::rc::Rc;
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement, TextMetrics};
 
 use crate::constants::{CANVAS_HEIGHT, CANVAS_HEIGHT_F64, CANVAS_WIDTH, CANVAS_WIDTH_F64};
+use crate::location_builder::LocationBuilder;
 use crate::model::{Position, Size, SpriteCell};
 use crate::sprite::{DrawingState, Sprite, TextOverlay};
 use crate::web_utils::{create_canvas, get_canvas_context};
 
+/// Blue tint of chilled Sprites.
+const CHILLED_FILTER: &str = "sepia(0.6) hue-rotate(170deg) saturate(2.5)";
+
+/// Shade swept over recharging cards.
+const RECHARGE_FILL: &str = "rgba(0, 0, 0, 0.55)";
+
+/// The drawing surface of the game, allowing the game to run with or without an actual canvas.
+pub trait Renderer {
+    fn clear(&self);
+
+    fn draw_sprite(&self, sprite: &Sprite);
+
+    fn draw_line(&self, start: &Position, to: &Position);
+}
+
+/// Renderer used when running without a browser (e.g tests / simulations), draws nothing.
+pub struct HeadlessPainter;
+
+impl Renderer for HeadlessPainter {
+    fn clear(&self) {}
+
+    fn draw_sprite(&self, _sprite: &Sprite) {}
+
+    fn draw_line(&self, _start: &Position, _to: &Position) {}
+}
+
 pub struct Painter {
     pub canvas: HtmlCanvasElement,
     pub context: CanvasRenderingContext2d,
@@ -20,36 +47,7 @@ impl Painter {
         Self { canvas, context }
     }
 
-    pub fn clear(&self) {
-        self.context
-            .clear_rect(0.0, 0.0, CANVAS_WIDTH_F64, CANVAS_HEIGHT_F64);
-    }
-
-    pub fn draw_sprite(&self, sprite: &Sprite) {
-        let cell = DrawingState::get_active_cell(sprite);
-
-        // Draw Sprite according to it's type.
-        if let Some(image) = &sprite.image {
-            let image_ref = image
-                .upgrade()
-                .expect("[Painter] - Cannot draw Image is not available");
-
-            self.draw_image(
-                &image_ref,
-                &sprite.position,
-                &sprite.drawing_state.offset,
-                cell,
-                sprite.drawing_state.scale,
-                sprite.drawing_state.alpha,
-                sprite.drawing_state.grayscale,
-            );
-        }
-
-        if let Some(text_overlay) = &sprite.text_overlay {
-            self.draw_text_overlay(text_overlay);
-        }
-    }
-
+    #[allow(clippy::too_many_arguments)]
     pub fn draw_image(
         &self,
         image: &Rc<HtmlImageElement>,
@@ -59,6 +57,7 @@ impl Painter {
         scale: f64,
         alpha: f64,
         grayscale: bool,
+        chilled: bool,
     ) {
         // Setting translate if defined, which will cause a "partial image" view.
         self.context.translate(-offset.left, -offset.top).unwrap();
@@ -66,6 +65,8 @@ impl Painter {
 
         if grayscale {
             self.context.set_filter("grayscale(1)");
+        } else if chilled {
+            self.context.set_filter(CHILLED_FILTER);
         }
 
         self.context
@@ -84,10 +85,23 @@ impl Painter {
 
         // Restoring translate
         self.context.translate(offset.left, offset.top).unwrap();
-        self.context.set_filter("grayscale(0)");
+        self.context.set_filter("none");
     }
 
-    pub fn draw_text_overlay(&self, text_overlay: &TextOverlay) {
+    /// Darkens the part of a card left to recharge, shrinking upwards as it recharges.
+    pub fn draw_recharge(&self, pos: &Position, cell: &SpriteCell, scale: f64, recharge: f64) {
+        self.context.save();
+        self.context.set_fill_style(&RECHARGE_FILL.into());
+        self.context.fill_rect(
+            pos.left,
+            pos.top,
+            cell.width * scale,
+            cell.height * scale * recharge.min(1.0),
+        );
+        self.context.restore();
+    }
+
+    pub fn draw_text_overlay(&self, text_overlay: &TextOverlay, sprite: &Sprite) {
         self.context.save();
         self.set_text_styles(text_overlay.size);
 
@@ -95,7 +109,19 @@ impl Painter {
             self.context.set_fill_style(&color.into());
         }
 
-        let position = &text_overlay.position.unwrap();
+        let position = &text_overlay.position.get().unwrap_or_else(|| {
+            // Placing text relative to the given source sprite, by it's measured size.
+            let text_size = Painter::measure_text(&text_overlay.text, text_overlay.size);
+            let position = LocationBuilder::text_overlay_location(
+                sprite,
+                text_size,
+                &text_overlay.location_type,
+            );
+
+            text_overlay.position.set(Some(position));
+
+            position
+        });
         let offset = &text_overlay.offset.unwrap_or_default();
 
         self.context
@@ -109,23 +135,6 @@ impl Painter {
         self.context.restore();
     }
 
-    pub fn draw_line(&self, start: &Position, to: &Position) {
-        self.context.save();
-        self.context.begin_path();
-
-        self.context.begin_path();
-
-        self.context.move_to(start.left, start.top);
-        self.context.line_to(to.left, to.top);
-        self.context.set_line_width(0.3);
-
-        self.context.close_path();
-
-        self.context.stroke();
-
-        self.context.restore();
-    }
-
     pub fn measure_text(text: &str, size: usize) -> Size {
         let measure_painter = Painter::get_measurements_painter(Size::new(200.0, 200.0));
 
@@ -137,37 +146,6 @@ impl Painter {
         text_size
     }
 
-    pub fn in_path(
-        outline: &Vec<Position>,
-        point: &Position,
-        context: &CanvasRenderingContext2d,
-    ) -> bool {
-        if outline.is_empty() {
-            return false;
-        }
-
-        context.save();
-        context.set_global_alpha(0.0);
-        context.begin_path();
-
-        let first = outline.get(0).unwrap();
-        context.move_to(first.left, first.top);
-
-        // Draw outline within context
-        outline
-            .iter()
-            .skip(1)
-            .for_each(|path| context.line_to(path.left, path.top));
-
-        context.close_path();
-        context.stroke();
-
-        context.restore();
-
-        // Check rather if point is within that shape.
-        context.is_point_in_path_with_f64(point.left, point.top)
-    }
-
     pub fn set_text_styles(&self, size: usize) {
         let font_size = format!("{}px Kavivanar", size);
 
@@ -187,3 +165,62 @@ impl Painter {
         }
     }
 }
+
+impl Renderer for Painter {
+    fn clear(&self) {
+        self.context
+            .clear_rect(0.0, 0.0, CANVAS_WIDTH_F64, CANVAS_HEIGHT_F64);
+    }
+
+    fn draw_sprite(&self, sprite: &Sprite) {
+        let cell = DrawingState::get_active_cell(sprite);
+
+        // Draw Sprite according to it's type.
+        if let Some(image) = &sprite.image {
+            let image_ref = image
+                .upgrade()
+                .expect("[Painter] - Cannot draw Image is not available");
+
+            self.draw_image(
+                &image_ref,
+                &sprite.position,
+                &sprite.drawing_state.offset,
+                cell,
+                sprite.drawing_state.scale,
+                sprite.drawing_state.alpha,
+                sprite.drawing_state.grayscale,
+                sprite.drawing_state.chilled,
+            );
+        }
+
+        if sprite.drawing_state.recharge > 0.0 {
+            self.draw_recharge(
+                &sprite.position,
+                cell,
+                sprite.drawing_state.scale,
+                sprite.drawing_state.recharge,
+            );
+        }
+
+        if let Some(text_overlay) = &sprite.text_overlay {
+            self.draw_text_overlay(text_overlay, sprite);
+        }
+    }
+
+    fn draw_line(&self, start: &Position, to: &Position) {
+        self.context.save();
+        self.context.begin_path();
+
+        self.context.begin_path();
+
+        self.context.move_to(start.left, start.top);
+        self.context.line_to(to.left, to.top);
+        self.context.set_line_width(0.3);
+
+        self.context.close_path();
+
+        self.context.stroke();
+
+        self.context.restore();
+    }
+}
@@ -0,0 +1,37 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// A small seedable pseudo random generator (SplitMix64), used instead of `Math.random()`
+/// so game randomness is reproducible and available outside the browser.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// Returns a float within [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a whole number within [min, max] (both inclusive).
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        let min = min.ceil();
+        let max = max.floor();
+
+        (self.next_f64() * (max - min + 1.0)).floor() + min
+    }
+}
@@ -0,0 +1,94 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::game::Game;
+use crate::model::SpriteType;
+use crate::resource_loader::ResourceKind;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RechargeState {
+    /// Recharging battle cards, each represented as a card Sprite id / recharge start / recharge end tuple.
+    pub cards: Vec<(String, f64, f64)>,
+}
+
+impl RechargeState {
+    pub fn new() -> Self {
+        RechargeState { cards: vec![] }
+    }
+}
+
+/// Keeps battle cards from being planted again until their recharge time passes.
+pub struct RechargeManager;
+
+impl RechargeManager {
+    pub fn tick(game: &mut Game) {
+        let now = game.game_time.time;
+
+        // Cards are gone once the battle is over or their plants re-chosen.
+        let recharging = game
+            .state
+            .recharge_state
+            .cards
+            .iter()
+            .filter(|(card_id, _start, end)| {
+                *end > now && game.sprites.iter().any(|sprite| &sprite.id == card_id)
+            })
+            .cloned()
+            .collect::<Vec<(String, f64, f64)>>();
+
+        game.get_sprites_by_type(&SpriteType::Card)
+            .iter_mut()
+            .for_each(|card| {
+                card.drawing_state.recharge = recharging
+                    .iter()
+                    .find(|(card_id, _start, _end)| card_id == &card.id)
+                    .map_or(0.0, |(_card_id, start, end)| (end - now) / (end - start));
+            });
+
+        game.state.recharge_state.cards = recharging;
+    }
+
+    /// Starts recharging the battle card the planted plant was taken from.
+    pub fn on_plant(game: &mut Game, card_id: &str) {
+        // Conveyor cards are used up as planted, rather than recharged.
+        if game.state.get_level().rules.conveyor.is_some() {
+            return;
+        }
+
+        let card_name = game
+            .sprites
+            .iter()
+            .find(|sprite| sprite.id == card_id)
+            .map(|card| card.name.clone());
+
+        if let Some(card_name) = card_name {
+            let now = game.game_time.time;
+            let recharge = game
+                .resources
+                .get_resource(&card_name, &ResourceKind::Card)
+                .data
+                .recharge;
+
+            if recharge <= 0.0 {
+                return;
+            }
+
+            game.state
+                .recharge_state
+                .cards
+                .push((card_id.to_string(), now, now + recharge));
+
+            Self::tick(game);
+        }
+    }
+
+    pub fn is_ready(game: &Game, card_id: &String) -> bool {
+        let now = game.game_time.time;
+
+        !game
+            .state
+            .recharge_state
+            .cards
+            .iter()
+            .any(|(recharging_id, _start, end)| recharging_id == card_id && *end > now)
+    }
+}
@@ -0,0 +1,168 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::engine::EngineError;
+use crate::game::Game;
+use crate::model::{GameKeyEvent, GameMouseEvent, Position};
+use crate::storage::Storage;
+
+const REPLAY_KEY: &str = "plants-vs-zombies/replay";
+
+/// Longest recordable level, an hour long at 60fps.
+const MAX_REPLAY_FRAMES: usize = 60 * 60 * 60;
+
+/// A user input handled by the game.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ReplayInput {
+    Mouse(GameMouseEvent, Position),
+    Key(GameKeyEvent),
+}
+
+/// A user input recorded during a level, alongside the frame it was handled at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    pub frame: usize,
+    pub input: ReplayInput,
+}
+
+/// Frames in a row lasting the same duration (ms).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrameRun {
+    pub duration: f64,
+    pub count: usize,
+}
+
+/// A recording of a played level, holding everything required to reproduce it:
+/// The level and seed it was started with, each frame duration and the user inputs in between.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub level_id: String,
+    pub seed: u64,
+    /// The game time the level was started at.
+    pub start_time: f64,
+    pub start_mouse_position: Position,
+    /// Recorded frames durations, as browsers frame rate is never steady.
+    /// Kept only when changing from the previous frame, thus a steady frame rate is recorded at once.
+    pub frames: Vec<FrameRun>,
+    /// The number of recorded frames.
+    pub frame_count: usize,
+    pub events: Vec<ReplayEvent>,
+}
+
+impl Replay {
+    pub fn new(level_id: &str, seed: u64, start_time: f64, mouse_position: Position) -> Self {
+        Replay {
+            level_id: level_id.to_string(),
+            seed,
+            start_time,
+            start_mouse_position: mouse_position,
+            frames: vec![],
+            frame_count: 0,
+            events: vec![],
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, EngineError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn load(storage: &dyn Storage) -> Option<Replay> {
+        Self::from_json(&storage.get(REPLAY_KEY)?).ok()
+    }
+
+    pub fn store(&self, storage: &mut dyn Storage) -> Result<(), EngineError> {
+        storage.set(REPLAY_KEY, &self.to_json())
+    }
+
+    /// Records a frame duration, returns whether it was recorded as the replay is within it's frames limit.
+    pub fn record_frame(&mut self, duration: f64) -> bool {
+        if self.frame_count >= MAX_REPLAY_FRAMES {
+            return false;
+        }
+
+        match self.frames.last_mut() {
+            Some(run) if run.duration == duration => run.count += 1,
+            _ => self.frames.push(FrameRun { duration, count: 1 }),
+        }
+        self.frame_count += 1;
+
+        true
+    }
+
+    /// Records a user input, handled before the upcoming frame.
+    pub fn record_event(&mut self, input: ReplayInput) {
+        self.events.push(ReplayEvent {
+            frame: self.frame_count,
+            input,
+        });
+    }
+}
+
+/// Plays a `Replay` back, feeding it's events through the game mouse and keyboard events handling.
+pub struct ReplayPlayer {
+    replay: Replay,
+    frame: usize,
+    /// The frame run played, alongside the frames played of it so far.
+    run: usize,
+    run_frame: usize,
+    next_event: usize,
+}
+
+impl ReplayPlayer {
+    pub fn new(replay: Replay) -> Self {
+        ReplayPlayer {
+            replay,
+            frame: 0,
+            run: 0,
+            run_frame: 0,
+            next_event: 0,
+        }
+    }
+
+    /// Starts the replayed level, restoring the game time and mouse position it was recorded with.
+    pub fn start(&self, game: &mut Game) {
+        game.game_time.time = self.replay.start_time;
+        game.game_time.last_timestamp = self.replay.start_time;
+        game.mouse_position = self.replay.start_mouse_position;
+
+        game.game_time.set_paused(false);
+
+        game.start_level_by_id(&self.replay.level_id, self.replay.seed);
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.frame >= self.replay.frame_count
+    }
+
+    /// Handles the events recorded ahead of the upcoming frame.
+    /// Returns the frame duration to advance the game by, or None once the replay has ended.
+    pub fn next_frame(&mut self, game: &mut Game) -> Option<f64> {
+        let run = *self.replay.frames.get(self.run)?;
+
+        while let Some(recorded) = self
+            .replay
+            .events
+            .get(self.next_event)
+            .filter(|recorded| recorded.frame == self.frame)
+        {
+            match recorded.input {
+                ReplayInput::Mouse(event, position) => game.handle_mouse_event(event, position),
+                ReplayInput::Key(event) => game.handle_key_event(event),
+            }
+            self.next_event += 1;
+        }
+
+        self.frame += 1;
+        self.run_frame += 1;
+
+        if self.run_frame == run.count {
+            self.run += 1;
+            self.run_frame = 0;
+        }
+
+        Some(run.duration)
+    }
+}
@@ -2,6 +2,9 @@ mod image;
 mod model;
 
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
 use std::rc::{Rc, Weak};
 
 use futures::future::join_all;
@@ -11,7 +14,7 @@ use wasm_bindgen_futures::JsFuture;
 use web_sys::{HtmlImageElement, Response};
 
 use crate::engine::EngineError;
-use crate::model::{LevelData, SpriteCell, SpriteData};
+use crate::model::{LevelData, SoundData, SpriteCell, SpriteData};
 use crate::resource_loader::image::ImageFuture;
 pub use crate::resource_loader::model::{ResourceDataType, ResourceKind};
 use crate::web_utils::window;
@@ -29,6 +32,8 @@ pub struct Resources {
     pub cells: HashMap<String, Vec<SpriteCell>>,
     pub data: HashMap<String, SpriteData>,
     pub level_data: HashMap<String, LevelData>,
+    pub campaigns: HashMap<String, Vec<String>>,
+    pub sounds: HashMap<String, SoundData>,
     pub images: HashMap<String, Rc<HtmlImageElement>>,
 }
 
@@ -38,6 +43,8 @@ impl Resources {
             cells: HashMap::new(),
             data: HashMap::new(),
             level_data: HashMap::new(),
+            campaigns: HashMap::new(),
+            sounds: HashMap::new(),
             images: HashMap::new(),
         }
     }
@@ -45,12 +52,17 @@ impl Resources {
     pub fn get_resource(&self, name: &str, kind: &ResourceKind) -> Resource {
         let resource_key = format!("{}/{}", kind.value(), name);
 
+        let data = self.data.get(&resource_key).unwrap();
+        let cell_key = match &data.cell {
+            Some(cell_name) => format!("{}/{}", kind.value(), cell_name),
+            None => resource_key.clone(),
+        };
+
         let cell = self
             .cells
-            .get(&resource_key)
+            .get(&cell_key)
             .unwrap_or_else(|| panic!("Cannot find Sprite cell - {} / {:?} ", name, kind));
 
-        let data = self.data.get(&resource_key).unwrap();
         let image = self.images.get(kind.value());
 
         Resource {
@@ -71,6 +83,32 @@ impl Resources {
         }
     }
 
+    /// Returns the ordered level ids of a campaign.
+    pub fn get_campaign(&self, name: &str) -> Vec<String> {
+        let resource_key = format!("{}/{}", ResourceKind::Campaign.value(), name);
+
+        self.campaigns
+            .get(&resource_key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the level following the given one within it's campaign.
+    pub fn next_level_id(&self, level_id: &str) -> Option<String> {
+        self.campaigns.values().find_map(|levels| {
+            let index = levels.iter().position(|id| id == level_id)?;
+
+            levels.get(index + 1).cloned()
+        })
+    }
+
+    /// Returns the named sound data, None when the sound has no audio file.
+    pub fn get_sound(&self, name: &str) -> Option<&SoundData> {
+        let resource_key = format!("{}/{}", ResourceKind::Sound.value(), name);
+
+        self.sounds.get(&resource_key)
+    }
+
     pub fn get_level_data(&self, level_id: &str) -> LevelData {
         let resource_key = format!("{}/{}", ResourceKind::Level.value(), level_id);
 
@@ -113,6 +151,14 @@ impl ResourceLoader {
             .load_json_resources::<LevelData>(vec![ResourceKind::Level], ResourceDataType::Data)
             .await;
 
+        let campaigns = self
+            .load_json_resources::<Vec<String>>(vec![ResourceKind::Campaign], ResourceDataType::Data)
+            .await;
+
+        let sounds = self
+            .load_json_resources::<SoundData>(vec![ResourceKind::Sound], ResourceDataType::Data)
+            .await;
+
         let images = self
             .load_image_resources(vec![
                 ResourceKind::Card,
@@ -126,10 +172,90 @@ impl ResourceLoader {
             cells,
             data,
             level_data,
+            campaigns,
+            sounds,
             images,
         }
     }
 
+    /// Loads the game data directly from an assets directory, without any images.
+    /// Used when the game runs outside the browser (e.g tests / simulations).
+    pub fn load_from_dir(&self, assets_path: &Path) -> Result<Resources, EngineError> {
+        let sprite_kinds = vec![
+            ResourceKind::Card,
+            ResourceKind::Plant,
+            ResourceKind::Zombie,
+            ResourceKind::Interface,
+        ];
+
+        let cells = self.read_json_resources::<Vec<SpriteCell>>(
+            assets_path,
+            &sprite_kinds,
+            ResourceDataType::Cell,
+        )?;
+
+        let data = self.read_json_resources::<SpriteData>(
+            assets_path,
+            &sprite_kinds,
+            ResourceDataType::Data,
+        )?;
+
+        let level_data = self.read_json_resources::<LevelData>(
+            assets_path,
+            &[ResourceKind::Level],
+            ResourceDataType::Data,
+        )?;
+
+        let campaigns = self.read_json_resources::<Vec<String>>(
+            assets_path,
+            &[ResourceKind::Campaign],
+            ResourceDataType::Data,
+        )?;
+
+        let sounds = self.read_json_resources::<SoundData>(
+            assets_path,
+            &[ResourceKind::Sound],
+            ResourceDataType::Data,
+        )?;
+
+        Ok(Resources {
+            cells,
+            data,
+            level_data,
+            campaigns,
+            sounds,
+            images: HashMap::new(),
+        })
+    }
+
+    fn read_json_resources<T>(
+        &self,
+        assets_path: &Path,
+        resource_kinds: &[ResourceKind],
+        data_type: ResourceDataType,
+    ) -> Result<HashMap<String, T>, EngineError>
+    where
+        for<'a> T: Deserialize<'a>,
+    {
+        let mut jsons_map = HashMap::new();
+
+        for kind in resource_kinds.iter() {
+            let qualified_path =
+                assets_path
+                    .join("json")
+                    .join(format!("{}-{}.json", kind.value(), data_type.value()));
+
+            let reader = BufReader::new(File::open(qualified_path)?);
+            let json_items: HashMap<String, T> = serde_json::from_reader(reader)?;
+
+            for (key, value) in json_items {
+                jsons_map.insert(format!("{}/{}", kind.value(), key), value);
+            }
+        }
+
+        Ok(jsons_map)
+    }
+
     async fn load_json_resources<T>(
         &self,
         resource_kinds: Vec<ResourceKind>,
@@ -189,7 +315,7 @@ impl ResourceLoader {
 
         let images: HashMap<String, Rc<HtmlImageElement>> = kinds
             .iter()
-            .zip(promise_all.into_iter())
+            .zip(promise_all)
             .filter(|(_key, value)| (*value).is_ok())
             .map(|(key, value)| (key.value().to_string(), Rc::new(value.unwrap())))
             .collect();
@@ -1,10 +1,14 @@
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ResourceKind {
     Card,
     Interface,
     Plant,
     Zombie,
     Level,
+    Campaign,
+    Sound,
 }
 
 impl ResourceKind {
@@ -15,6 +19,8 @@ impl ResourceKind {
             ResourceKind::Plant => "plant",
             ResourceKind::Zombie => "zombie",
             ResourceKind::Level => "level",
+            ResourceKind::Campaign => "campaign",
+            ResourceKind::Sound => "sound",
         }
     }
 }
@@ -22,7 +28,6 @@ impl ResourceKind {
 pub enum ResourceDataType {
     Cell,
     Data,
-    Image,
 }
 
 impl ResourceDataType {
@@ -30,7 +35,6 @@ impl ResourceDataType {
         match *self {
             ResourceDataType::Cell => "cell",
             ResourceDataType::Data => "data",
-            ResourceDataType::Image => "image",
         }
     }
 }
@@ -0,0 +1,127 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::board::BoardLocation;
+use crate::game::Game;
+use crate::resource_loader::ResourceKind;
+use crate::scene::BattleScene;
+use crate::sprite::Sprite;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConveyorState {
+    /// None while there is no battle running, as deliveries are counted from the battle start.
+    pub last_delivered: Option<f64>,
+}
+
+impl ConveyorState {
+    pub fn new() -> Self {
+        ConveyorState {
+            last_delivered: None,
+        }
+    }
+}
+
+/// Applies the current level rules upon the battle flow.
+pub struct RulesManager;
+
+impl RulesManager {
+    /// Gives the level plant cards as is, in place of choosing them.
+    pub fn give_plant_cards(game: &mut Game) {
+        game.state
+            .get_level()
+            .plant_cards
+            .iter()
+            .for_each(|plant| Self::add_card(game, plant.trim()));
+    }
+
+    pub fn start_battle(game: &mut Game) {
+        let rules = game.state.get_level().rules;
+
+        game.state.conveyor_state.last_delivered = Some(game.game_time.time);
+
+        rules.planted.iter().for_each(|placement| {
+            let plant = Sprite::create_sprite(
+                &placement.name,
+                &ResourceKind::Plant,
+                &game.resources,
+                &mut game.random,
+            )
+            .remove(0);
+            let plant_id = plant.id.clone();
+
+            game.add_sprite(plant);
+
+            BattleScene::place_plant(
+                game,
+                &plant_id,
+                &BoardLocation::new(placement.row, placement.col),
+            );
+        });
+    }
+
+    /// Stops the conveyor deliveries, as the battle is over.
+    pub fn end_battle(game: &mut Game) {
+        game.state.conveyor_state = ConveyorState::new();
+    }
+
+    /// Delivers conveyor cards along the battle.
+    pub fn tick(game: &mut Game) {
+        let now = game.game_time.time;
+        let conveyor = match game.state.get_level().rules.conveyor {
+            Some(conveyor) => conveyor,
+            None => return,
+        };
+
+        let last_delivered = match game.state.conveyor_state.last_delivered {
+            Some(last_delivered) => last_delivered,
+            None => return,
+        };
+
+        if now - last_delivered < conveyor.interval {
+            return;
+        }
+
+        game.state.conveyor_state.last_delivered = Some(now);
+
+        if conveyor.plants.is_empty() || game.state.selected_seeds.len() >= conveyor.capacity {
+            return;
+        }
+
+        let index = game.random.range(0.0, (conveyor.plants.len() - 1) as f64) as usize;
+
+        Self::add_card(game, conveyor.plants[index].trim());
+
+        BattleScene::make_plant_cards_draggable(game);
+    }
+
+    /// Uses up the conveyor card the planted plant was taken from.
+    pub fn on_plant(game: &mut Game, card_id: &str) {
+        if game.state.get_level().rules.conveyor.is_none() {
+            return;
+        }
+
+        let used_card = game
+            .state
+            .selected_seeds
+            .iter()
+            .position(|(_seed_id, selected_card_id)| selected_card_id == card_id);
+
+        if let Some(index) = used_card {
+            let (_seed_id, card_id) = game.state.selected_seeds.remove(index);
+
+            game.remove_sprite_by_id(&card_id);
+            BattleScene::update_selected_cards_layout(game);
+        }
+    }
+
+    fn add_card(game: &mut Game, plant_name: &str) {
+        let card_id = BattleScene::add_plant_card(game, plant_name);
+
+        // Conveyor cards are free, the belt limits the plants rather than the sun.
+        if game.state.get_level().rules.conveyor.is_some() {
+            game.get_sprite_by_id(&card_id).sun_cost = 0;
+        }
+
+        // No seed is involved, the card stands for both.
+        game.state.selected_seeds.push((card_id.clone(), card_id));
+    }
+}
@@ -1,11 +1,16 @@
+use crate::board::{Board, BoardLocation};
 use crate::game::Game;
 use crate::location_builder::LocationBuilder;
-use crate::model::BehaviorType::Walk;
 use crate::model::Callback::PlantCardClick;
-use crate::model::{BehaviorData, BehaviorType, Callback, Plant, Position, SelectedSeed, SpriteType};
+use crate::model::{
+    BehaviorData, BehaviorType, Callback, LaneData, Position, SelectedSeed, ShootDirection,
+    SpriteCell, SpriteType,
+};
+use crate::random::Random;
 use crate::resource_loader::ResourceKind;
 use crate::scene::PlantsChooser;
-use crate::sprite::{BehaviorManager, Click, DrawingState, Scroll, Sprite};
+use crate::sprite::{BehaviorManager, Click, Collision, DrawingState, Scroll, Sprite, Walk};
+use crate::wave_manager::WaveManager;
 
 pub struct BattleScene;
 
@@ -21,6 +26,7 @@ impl BattleScene {
             ],
             &ResourceKind::Interface,
             &game.resources,
+            &mut game.random,
         );
 
         // Trigger background scroll
@@ -34,38 +40,101 @@ impl BattleScene {
         game.add_sprites(sprites.as_mut());
     }
 
-    fn build_zombies(game: &mut Game) {
-        let mut zombies = Sprite::create_sprites(
-            game.state
-                .get_level()
-                .zombies
-                .iter()
-                .map(|zombie_name| zombie_name.trim())
-                .collect(),
-            &ResourceKind::Zombie,
-            &game.resources,
-        );
+    fn build_flag_meter(game: &mut Game) {
+        let flag_marks = WaveManager::flag_marks(&game.state.get_level());
+        let (meter_position, meter_cell) = Self::flag_meter_placement(game);
 
-        BehaviorManager::toggle_behaviors(
-            &zombies,
-            &[BehaviorType::Animate],
-            true,
-            game.game_time.time,
+        let mut flags = Sprite::create_sprites(
+            vec!["FlagMeterParts2"; flag_marks.len()],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
         );
 
-        // Set Zombie random start position
-        zombies.iter_mut().enumerate().for_each(|(index, zombie)| {
-            let zombie_cell = DrawingState::get_active_cell(zombie);
+        // Meter is filled from right to left, placing each flag at it's progress point.
+        flags.iter_mut().zip(flag_marks).for_each(|(flag, mark)| {
+            let flag_cell = DrawingState::get_active_cell(flag);
 
-            zombie.update_position(LocationBuilder::zombie_location(zombie_cell, index))
+            flag.update_position(Position::new(
+                meter_position.top - (flag_cell.height - meter_cell.height),
+                meter_position.left + meter_cell.width * (1.0 - mark) - flag_cell.width / 2.0,
+            ));
         });
 
-        game.add_sprites(zombies.as_mut());
+        game.add_sprites(flags.as_mut());
+    }
+
+    /// Rebuilds the flag meter upon the current level waves, e.g once they are renewed.
+    pub fn reset_flag_meter(game: &mut Game) {
+        game.remove_sprites_by_name(vec!["FlagMeterParts2", "FlagMeterFull"]);
+
+        let head = game.get_sprite_by_name_and_type("FlagMeterParts1", &SpriteType::Interface);
+        head.update_position(head.origin_position);
+
+        Self::build_flag_meter(game);
+    }
+
+    pub fn update_flag_meter(game: &mut Game) {
+        let progress = WaveManager::progress(game);
+        let (meter_position, meter_cell) = Self::flag_meter_placement(game);
+        let filled_width = meter_cell.width * progress;
+
+        let has_fill = game
+            .sprites
+            .iter()
+            .any(|sprite| sprite.name == "FlagMeterFull");
+
+        if !has_fill {
+            let mut fill = Sprite::create_sprite(
+                "FlagMeterFull",
+                &ResourceKind::Interface,
+                &game.resources,
+                &mut game.random,
+            );
+
+            game.add_sprites(fill.as_mut());
+        }
+
+        // Shows the right part of the full meter, according to current progress.
+        let full_cell = game
+            .resources
+            .get_cell("FlagMeterFull", &ResourceKind::Interface)
+            .remove(0);
+        let fill = game.get_sprite_by_name_and_type("FlagMeterFull", &SpriteType::Interface);
+
+        fill.drawing_state.cells = vec![SpriteCell {
+            left: full_cell.left + full_cell.width - filled_width,
+            width: filled_width,
+            ..full_cell.clone()
+        }];
+        fill.update_position(Position::new(
+            meter_position.top,
+            meter_position.left + meter_cell.width - filled_width,
+        ));
+
+        // Zombie head leads the progress
+        let head = game.get_sprite_by_name_and_type("FlagMeterParts1", &SpriteType::Interface);
+        let head_position = head.origin_position;
+
+        head.update_position(Position::new(
+            head_position.top,
+            head_position.left - filled_width,
+        ));
+    }
+
+    fn flag_meter_placement(game: &mut Game) -> (Position, SpriteCell) {
+        let meter = game.get_sprite_by_name_and_type("FlagMeterEmpty", &SpriteType::Interface);
+
+        (meter.position, DrawingState::get_active_cell(meter).clone())
     }
 
     fn build_lawn_cleaners(game: &mut Game) {
-        let mut lawn_cleaners =
-            Sprite::create_sprite("LawnCleaner", &ResourceKind::Interface, &game.resources);
+        let mut lawn_cleaners = Sprite::create_sprite(
+            "LawnCleaner",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
 
         lawn_cleaners
             .iter_mut()
@@ -75,11 +144,39 @@ impl BattleScene {
     }
 
     pub fn build_zombie_head(game: &mut Game, zombie_id: &String) {
-        let zombie_adjustment_position = Position::new(-60.0, 65.0);
+        Self::build_falling_part(game, zombie_id, "ZombieHead", Position::new(-60.0, 65.0));
+    }
+
+    /// Drops the armor of the zombie, once it's broken.
+    pub fn build_armor_drop(game: &mut Game, zombie_id: &String) {
+        let drop = game
+            .get_sprite_by_id(zombie_id)
+            .attack_state
+            .armor
+            .as_ref()
+            .and_then(|armor| armor.drop.clone());
+
+        if let Some(drop) = drop {
+            Self::build_falling_part(game, zombie_id, &drop, Position::new(-10.0, 20.0));
+        }
+    }
+
+    /// Builds a part falling off the zombie, e.g it's head.
+    fn build_falling_part(
+        game: &mut Game,
+        zombie_id: &String,
+        name: &str,
+        zombie_adjustment_position: Position,
+    ) {
         let zombie_position = game.get_sprite_by_id(zombie_id).position;
         let now = game.game_time.time;
 
-        let mut sprites = Sprite::create_sprite("ZombieHead", &ResourceKind::Zombie, &game.resources);
+        let mut sprites = Sprite::create_sprite(
+            name,
+            &ResourceKind::Zombie,
+            &game.resources,
+            &mut game.random,
+        );
 
         sprites.iter_mut().for_each(|zombie| {
             zombie.update_position(Position::new(
@@ -90,7 +187,16 @@ impl BattleScene {
             zombie.sprite_type = SpriteType::Interface; // Avoid detected as Zombie
         });
 
-        BehaviorManager::toggle_behaviors(&sprites, &[BehaviorType::Animate, Walk], true, now);
+        BehaviorManager::toggle_behaviors(
+            &sprites,
+            &[
+                BehaviorType::Animate,
+                BehaviorType::Walk,
+                BehaviorType::Fade,
+            ],
+            true,
+            now,
+        );
 
         game.add_sprites(sprites.as_mut());
     }
@@ -98,7 +204,7 @@ impl BattleScene {
     pub fn prepare(game: &mut Game) {
         Self::build_background(game);
 
-        Self::build_zombies(game);
+        Self::build_flag_meter(game);
     }
 
     pub fn enter(game: &mut Game) {
@@ -140,13 +246,24 @@ impl BattleScene {
 
     pub fn battle_callout(game: &mut Game) {
         let mut scene_sprites = Sprite::create_sprites(
-            vec!["SunScore", "Shovel", "ShovelBack"],
+            vec![
+                "SunScore",
+                "Shovel",
+                "ShovelBack",
+                "PauseButton",
+                "SpeedButton",
+            ],
             &ResourceKind::Interface,
             &game.resources,
+            &mut game.random,
         );
 
-        let mut battle_callout =
-            Sprite::create_sprite("BattleCallout", &ResourceKind::Interface, &game.resources);
+        let mut battle_callout = Sprite::create_sprite(
+            "BattleCallout",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
 
         BehaviorManager::toggle_behaviors(
             &battle_callout,
@@ -159,20 +276,15 @@ impl BattleScene {
 
         game.add_sprites(battle_callout.as_mut());
         game.add_sprites(scene_sprites.as_mut());
+
+        Self::update_speed_button(game);
     }
 
     pub fn start(game: &mut Game) {
-        game.get_sprites_by_type(&SpriteType::Zombie)
-            .iter_mut()
-            .for_each(|zombie| {
-                zombie.update_swap_cell(0);
-                zombie.toggle_walking(true);
-            });
-
         game.toggle_game_behavior(true, &[BehaviorType::Collision]);
     }
 
-    pub fn create_draggable_plant(game: &mut Game, sprite_id: &String) {
+    pub fn create_draggable_plant(game: &mut Game, sprite_id: &String) -> String {
         let mouse = game.mouse_position;
         let card_sprite = game.get_sprite_by_id(sprite_id);
 
@@ -180,8 +292,13 @@ impl BattleScene {
         let original_position = card_sprite.position;
         let plant_name = card_sprite.name.clone();
 
-        let mut plant =
-            Sprite::create_sprite(&plant_name, &ResourceKind::Plant, &game.resources).remove(0);
+        let mut plant = Sprite::create_sprite(
+            &plant_name,
+            &ResourceKind::Plant,
+            &game.resources,
+            &mut game.random,
+        )
+        .remove(0);
         let plant_cell = DrawingState::get_active_cell(&plant);
 
         // Original card can be wider than the actual Plant
@@ -203,19 +320,110 @@ impl BattleScene {
             original_position.left + drag_adjustment,
         ));
         plant.sun_cost = card_sun_cost;
+        plant.source_card = Some(sprite_id.clone());
         plant.order = 10; // TODO, Drag order based on behavior
 
+        let plant_id = plant.id.clone();
         game.add_sprite(plant);
+
+        plant_id
+    }
+
+    /// Whether the plant card is clickable in battle, as opposed to while choosing plants.
+    pub fn is_plant_card_active(game: &mut Game, card_id: &String) -> bool {
+        let card = game.get_sprite_by_id(card_id);
+
+        BehaviorManager::find_sprite_behavior(card, BehaviorType::Click)
+            .and_then(|click| {
+                click
+                    .as_any()
+                    .downcast_mut::<Click>()
+                    .map(|click| click.callback)
+            })
+            .is_some_and(|callback| matches!(callback, PlantCardClick))
+    }
+
+    /// Centers the given sprite around the mouse, so it's Drag behavior follows it right away.
+    pub fn attach_to_mouse(game: &mut Game, sprite_id: &String) {
+        let mouse = game.mouse_position;
+        let sprite = game.get_sprite_by_id(sprite_id);
+        let cell = DrawingState::get_active_cell(sprite);
+
+        sprite.update_position(Position::new(
+            mouse.top - cell.height / 2.0,
+            mouse.left - cell.width / 2.0,
+        ));
+    }
+
+    pub fn pick_shovel(game: &mut Game) {
+        let has_shovel = game
+            .sprites
+            .iter()
+            .any(|sprite| sprite.name == "Shovel" && sprite.sprite_type == SpriteType::Interface);
+
+        if !has_shovel {
+            return;
+        }
+
+        Self::cancel_drag(game);
+        Self::allow_shovel_drag(game);
+
+        let shovel_id = game
+            .get_sprite_by_name_and_type("Shovel", &SpriteType::Interface)
+            .id
+            .clone();
+
+        Self::attach_to_mouse(game, &shovel_id);
+    }
+
+    /// Cancels the currently dragged plant or shovel, without planting or digging.
+    pub fn cancel_drag(game: &mut Game) {
+        let now = game.game_time.time;
+
+        let dragged_plants =
+            game.sprites
+                .iter()
+                .filter(|sprite| sprite.sprite_type == SpriteType::Plant)
+                .filter(|sprite| {
+                    sprite.mutable_behaviors().iter().any(|behavior| {
+                        behavior.name() == BehaviorType::Drag && behavior.is_running()
+                    })
+                })
+                .map(|sprite| sprite.id.clone())
+                .collect::<Vec<String>>();
+
+        game.remove_sprites_by_id(dragged_plants);
+
+        let dragged_shovel = game
+            .sprites
+            .iter_mut()
+            .filter(|sprite| sprite.name == "Shovel" && sprite.sprite_type == SpriteType::Interface)
+            .filter_map(|shovel| BehaviorManager::find_sprite_behavior(shovel, BehaviorType::Drag))
+            .find(|drag| drag.is_running());
+
+        if let Some(drag) = dragged_shovel {
+            // Stopping without the drag end interaction, which digs the plant below.
+            drag.toggle(false, now);
+            drag.clean_interaction();
+
+            game.reset_shovel();
+        }
     }
 
     pub fn create_plant(game: &mut Game, sprite_id: &String) {
+        let location = Board::get_board_location(&game.mouse_position);
+
+        Self::place_plant(game, sprite_id, &location);
+    }
+
+    /// Places the plant upon the given board cell, bringing it to life.
+    pub fn place_plant(game: &mut Game, sprite_id: &String, location: &BoardLocation) {
         let now = game.game_time.time;
-        let mouse = game.mouse_position;
         let sprite = game.get_sprite_by_id(sprite_id);
         let plant_cell = DrawingState::get_active_cell(sprite);
 
-        // Clamp Plant sprite into closest cell bottom position.
-        let plant_position = LocationBuilder::plant_location(plant_cell, &mouse);
+        // Clamp Plant sprite into the cell bottom position.
+        let plant_position = Board::get_board_placement(plant_cell, location.row, location.col);
         sprite.update_position(plant_position);
 
         BehaviorManager::toggle_sprite_behaviors(
@@ -235,20 +443,45 @@ impl BattleScene {
         Self::toggle_cards_grayscale(game);
     }
 
-    pub fn create_bullet(game: &mut Game, sprite_id: &String) {
+    /// Fires a bullet of the plant along the given lane, into the lane row.
+    pub fn create_bullet(game: &mut Game, plant_id: &String, bullet_name: &str, lane: &LaneData) {
         let now = game.game_time.time;
-        let shooting_plant = game.get_sprite_by_id(sprite_id);
+        let shooting_plant = game.get_sprite_by_id(plant_id);
         let position = shooting_plant.position;
+        let plant_row = shooting_plant.board_location.row;
+        let lane_row = (plant_row as i32 + lane.row_offset) as usize;
 
-        let plant_name = &Plant::from_name(&shooting_plant.name.clone());
-        let bullet_type = Plant::bullet_type(plant_name);
-
-        let mut bullet =
-            Sprite::create_sprite(bullet_type, &ResourceKind::Plant, &game.resources).remove(0);
+        let mut bullet = Sprite::create_sprite(
+            bullet_name,
+            &ResourceKind::Plant,
+            &game.resources,
+            &mut game.random,
+        )
+        .remove(0);
 
         bullet.sprite_type = SpriteType::Bullet;
 
-        bullet.update_position(LocationBuilder::bullet_location(&position));
+        bullet.update_position(LocationBuilder::bullet_location(
+            &position, plant_row, lane_row,
+        ));
+
+        // Bullets shot backwards fly, and hit, the other way around.
+        if lane.direction == ShootDirection::Back {
+            let walk = BehaviorManager::get_sprite_behavior(&mut bullet, BehaviorType::Walk)
+                .as_any()
+                .downcast_mut::<Walk>()
+                .unwrap();
+            walk.velocity.x = -walk.velocity.x;
+
+            let collision =
+                BehaviorManager::get_sprite_behavior(&mut bullet, BehaviorType::Collision)
+                    .as_any()
+                    .downcast_mut::<Collision>()
+                    .unwrap();
+            let margin = collision.margin;
+            collision.margin.left = margin.right;
+            collision.margin.right = margin.left;
+        }
 
         BehaviorManager::toggle_sprite_behaviors(
             &bullet,
@@ -274,13 +507,69 @@ impl BattleScene {
     }
 
     pub fn zombies_won(game: &mut Game) {
-        let mut zombies_won =
-            Sprite::create_sprite("ZombiesWon", &ResourceKind::Interface, &game.resources);
+        let mut zombies_won = Sprite::create_sprite(
+            "ZombiesWon",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
 
         game.add_sprites(zombies_won.as_mut());
     }
 
-    fn make_plant_cards_draggable(game: &mut Game) {
+    pub fn toggle_pause_sign(game: &mut Game, paused: bool) {
+        if !paused {
+            return game.remove_sprites_by_name(vec!["PausedSign"]);
+        }
+
+        // Pausing must not affect the battle, thus the sign id is drawn apart from the game randomness.
+        let mut paused_sign = Sprite::create_sprite(
+            "PausedSign",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut Random::new(game.state.seed),
+        );
+
+        game.add_sprites(paused_sign.as_mut());
+    }
+
+    pub fn update_pause_button(game: &mut Game, paused: bool) {
+        let text = match paused {
+            true => "Resume",
+            false => "Pause",
+        };
+
+        Self::update_control_text(game, "PauseButton", text.to_string());
+    }
+
+    pub fn update_speed_button(game: &mut Game) {
+        let text = format!("{}x", game.game_time.speed);
+
+        Self::update_control_text(game, "SpeedButton", text);
+    }
+
+    fn update_control_text(game: &mut Game, name: &str, text: String) {
+        let control = game.sprites.iter_mut().find(|sprite| sprite.name == name);
+
+        if let Some(text_overlay) = control.and_then(|control| control.text_overlay.as_mut()) {
+            text_overlay.text = text;
+            // Re-measured upon the next draw
+            text_overlay.position.set(None);
+        }
+    }
+
+    pub fn level_won(game: &mut Game) {
+        let mut level_won = Sprite::create_sprites(
+            vec!["LevelWonBackground", "ContinueButton"],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+
+        game.add_sprites(level_won.as_mut());
+    }
+
+    pub fn make_plant_cards_draggable(game: &mut Game) {
         let mut plant_cards = game.get_sprites_by_type(&SpriteType::Card);
         plant_cards.iter_mut().for_each(|card| {
             let click = BehaviorManager::get_sprite_behavior(card, BehaviorType::Click);
@@ -299,11 +588,16 @@ impl BattleScene {
         })
     }
 
-    fn add_plant_card(game: &mut Game, seed_name: &str) -> String {
+    pub fn add_plant_card(game: &mut Game, seed_name: &str) -> String {
         let current_cards = game.state.selected_seeds.len();
 
-        let mut plant =
-            Sprite::create_sprite(seed_name, &ResourceKind::Card, &game.resources).remove(0);
+        let mut plant = Sprite::create_sprite(
+            seed_name,
+            &ResourceKind::Card,
+            &game.resources,
+            &mut game.random,
+        )
+        .remove(0);
 
         let plant_id = plant.id.clone();
 
@@ -315,7 +609,7 @@ impl BattleScene {
         plant_id
     }
 
-    fn update_selected_cards_layout(game: &mut Game) {
+    pub fn update_selected_cards_layout(game: &mut Game) {
         let mut count = 0;
         let selected_seeds = &game.state.selected_seeds.to_vec();
 
@@ -0,0 +1,76 @@
+use crate::game::Game;
+use crate::location_builder::LocationBuilder;
+use crate::model::{Position, Size};
+use crate::resource_loader::ResourceKind;
+use crate::scene::HomeScene;
+use crate::sprite::Sprite;
+
+const CHALLENGES_CAMPAIGN: &str = "challenges";
+
+/// Lists the challenges, levels which override the battle rules.
+pub struct ChallengeSelect;
+
+impl ChallengeSelect {
+    pub fn show(game: &mut Game) {
+        HomeScene::clear_selectors(game);
+
+        let mut sprites = Sprite::create_sprites(
+            vec!["ChallengeSelectBackground", "BackButton"],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+
+        let background_offset = &sprites.first().unwrap().position;
+
+        Self::build_challenges_layout(game, background_offset);
+
+        game.add_sprites(sprites.as_mut());
+    }
+
+    /// Whether the level is one of the challenges, which are all open from the start.
+    pub fn is_challenge(game: &Game, level_id: &str) -> bool {
+        game.resources
+            .get_campaign(CHALLENGES_CAMPAIGN)
+            .iter()
+            .any(|id| id == level_id)
+    }
+
+    fn build_challenges_layout(game: &mut Game, offset: &Position) {
+        let challenges = game.resources.get_campaign(CHALLENGES_CAMPAIGN);
+
+        let positions = LocationBuilder::create_row_layout(
+            &Position::new(offset.top + 90.0, offset.left + 40.0),
+            challenges.len(),
+            3,
+            Size::new(130.0, 60.0),
+        );
+
+        let mut buttons = Sprite::create_sprites(
+            vec!["ChallengeButton"; challenges.len()],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+
+        buttons
+            .iter_mut()
+            .zip(challenges.iter())
+            .enumerate()
+            .for_each(|(index, (button, level_id))| {
+                let level = game.resources.get_level_data(level_id);
+
+                button.update_position(positions[index]);
+
+                if let Some(text_overlay) = &mut button.text_overlay {
+                    text_overlay.text = level.title.unwrap_or_else(|| level_id.clone());
+                }
+
+                game.state
+                    .level_buttons
+                    .push((button.id.clone(), level_id.clone()));
+            });
+
+        game.add_sprites(buttons.as_mut());
+    }
+}
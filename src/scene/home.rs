@@ -29,17 +29,28 @@ impl HomeScene {
             scene_sprites_name,
             &ResourceKind::Interface,
             &game.resources,
+            &mut game.random,
         );
 
         // Adding scene sprites into game.
         game.add_sprites(sprites.as_mut());
     }
 
+    /// Clears home scene selectors, keeping it's background only.
+    pub fn clear_selectors(game: &mut Game) {
+        let mut home_sprites = Self::home_sprites();
+        home_sprites.retain(|name| name != &"SelectorBackground");
+        home_sprites.push("SelectorZombieHand");
+
+        game.remove_sprites_by_name(home_sprites);
+    }
+
     pub fn show_zombie_hand(game: &mut Game) {
         let mut zombie_hand = Sprite::create_sprite(
             "SelectorZombieHand",
             &ResourceKind::Interface,
             &game.resources,
+            &mut game.random,
         );
 
         // Activates zombie hand animation Cycle.
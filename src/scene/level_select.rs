@@ -0,0 +1,84 @@
+use crate::game::Game;
+use crate::location_builder::LocationBuilder;
+use crate::model::{Position, Size};
+use crate::resource_loader::ResourceKind;
+use crate::scene::HomeScene;
+use crate::sprite::Sprite;
+
+const ADVENTURE_CAMPAIGN: &str = "adventure";
+
+pub struct LevelSelect;
+
+impl LevelSelect {
+    pub fn show(game: &mut Game) {
+        HomeScene::clear_selectors(game);
+
+        let mut sprites = Sprite::create_sprites(
+            vec!["LevelSelectBackground", "BackButton"],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+
+        let background_offset = &sprites.first().unwrap().position;
+
+        Self::build_levels_layout(game, background_offset);
+
+        game.add_sprites(sprites.as_mut());
+    }
+
+    pub fn is_adventure(game: &Game, level_id: &str) -> bool {
+        game.resources
+            .get_campaign(ADVENTURE_CAMPAIGN)
+            .iter()
+            .any(|id| id == level_id)
+    }
+
+    /// Campaign levels are unlocked one by one, as the previous level is completed.
+    pub fn is_unlocked(game: &Game, level_id: &str) -> bool {
+        let levels = game.resources.get_campaign(ADVENTURE_CAMPAIGN);
+
+        match levels.iter().position(|id| id == level_id) {
+            Some(0) => true,
+            Some(index) => game.save_data.completed_levels.contains(&levels[index - 1]),
+            None => false,
+        }
+    }
+
+    fn build_levels_layout(game: &mut Game, offset: &Position) {
+        let levels = game.resources.get_campaign(ADVENTURE_CAMPAIGN);
+
+        let positions = LocationBuilder::create_row_layout(
+            &Position::new(offset.top + 90.0, offset.left + 50.0),
+            levels.len(),
+            4,
+            Size::new(95.0, 60.0),
+        );
+
+        let mut buttons = Sprite::create_sprites(
+            vec!["LevelButton"; levels.len()],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+
+        buttons
+            .iter_mut()
+            .zip(levels.iter())
+            .enumerate()
+            .for_each(|(index, (button, level_id))| {
+                button.update_position(positions[index]);
+                button.drawing_state.grayscale = !Self::is_unlocked(game, level_id);
+
+                if let Some(text_overlay) = &mut button.text_overlay {
+                    text_overlay.text = level_id.clone();
+                }
+
+                game.state
+                    .level_buttons
+                    .push((button.id.clone(), level_id.clone()));
+            });
+
+        game.add_sprites(buttons.as_mut());
+    }
+}
@@ -1,7 +1,11 @@
 mod battle;
+mod challenge_select;
 mod home;
+mod level_select;
 mod plants_chooser;
 
 pub use battle::BattleScene;
+pub use challenge_select::ChallengeSelect;
 pub use home::HomeScene;
+pub use level_select::LevelSelect;
 pub use plants_chooser::PlantsChooser;
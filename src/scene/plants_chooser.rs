@@ -2,6 +2,7 @@ use crate::game::Game;
 use crate::location_builder::LocationBuilder;
 use crate::model::{Position, Size, SpriteType};
 use crate::resource_loader::ResourceKind;
+use crate::scene::LevelSelect;
 use crate::sprite::Sprite;
 
 pub struct PlantsChooser;
@@ -16,6 +17,7 @@ impl PlantsChooser {
             Self::chooser_sprites(),
             &ResourceKind::Interface,
             &game.resources,
+            &mut game.random,
         );
 
         let chooser_background_offset = &sprites.first().unwrap().position;
@@ -45,36 +47,65 @@ impl PlantsChooser {
     }
 
     fn create_bottom_sun_score(game: &mut Game) {
-        let mut sun_score =
-            Sprite::create_sprite("SunScore", &ResourceKind::Interface, &game.resources).remove(0);
+        let mut sun_score = Sprite::create_sprite(
+            "SunScore",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        )
+        .remove(0);
 
         sun_score.position = Position::new(560.0, 98.0);
 
         game.add_sprite(sun_score);
     }
 
+    /// Adventure levels offer the plants unlocked along the campaign, followed by the ones the level brings in.
+    /// Other levels offer their own plants only.
+    fn offered_plants(game: &Game) -> Vec<String> {
+        let level = game.state.get_level();
+
+        if !LevelSelect::is_adventure(game, &level.name) {
+            return level.plant_cards;
+        }
+
+        let mut plants = game.save_data.unlocked_plants.clone();
+
+        level.plant_cards.into_iter().for_each(|plant| {
+            if !plants.contains(&plant) {
+                plants.push(plant);
+            }
+        });
+
+        plants
+    }
+
     fn build_seeds_layout(game: &mut Game, offset: &Position) {
         let seeds_scale = 0.725;
+        let plants = Self::offered_plants(game);
         let positions = LocationBuilder::create_row_layout(
             &Position::new(offset.top + 34.0, offset.left + 14.0),
-            game.state.get_level().plant_cards.len(),
+            plants.len(),
             6,
             Size::new(100.0 * seeds_scale, 60.0 * seeds_scale),
         );
 
-        let mut seeds = game
-            .state
-            .get_level()
-            .plant_cards
+        let mut seeds = plants
             .iter()
             .enumerate()
             .flat_map(|(index, card_name)| {
-                let mut card_sprite =
-                    Sprite::create_sprite(card_name.trim(), &ResourceKind::Card, &game.resources);
+                let mut card_sprite = Sprite::create_sprite(
+                    card_name.trim(),
+                    &ResourceKind::Card,
+                    &game.resources,
+                    &mut game.random,
+                );
 
                 card_sprite.iter_mut().for_each(|card| {
                     card.update_position(positions[index]);
                     card.sprite_type = SpriteType::Seed;
+                    // Drawn above the chooser background.
+                    card.order = 7;
                 });
 
                 card_sprite
@@ -0,0 +1,343 @@
+use std::path::Path;
+
+use crate::audio::SilentAudio;
+use crate::board::Board;
+use crate::engine::EngineError;
+use crate::game::Game;
+pub use crate::model::{
+    BattleStats, GameKeyEvent, GameMouseEvent, LevelData, LevelRules, Music, Position, SoundEffect,
+    SpriteType, SunData, SunSize, Velocity, WaveData,
+};
+use crate::model::{BehaviorType, SpriteCell};
+use crate::painter::HeadlessPainter;
+use crate::random::Random;
+pub use crate::replay::Replay;
+use crate::resource_loader::{ResourceKind, ResourceLoader};
+use crate::scene::BattleScene;
+pub use crate::snapshot::BattleSnapshot;
+use crate::sprite::{BehaviorManager, DrawingState, Sprite};
+pub use crate::storage::{MemoryStorage, SaveData, Settings};
+use crate::survival_manager::SurvivalManager;
+use crate::timers::ManualClock;
+use crate::wave_manager::WaveManager;
+
+/// Simulation frame duration (ms), equivalent of a 60fps browser animation frame.
+const FRAME_DURATION: f64 = 1000.0 / 60.0;
+
+/// A read only view of a Sprite within a running simulation.
+#[derive(Debug, Clone)]
+pub struct SpriteSnapshot {
+    pub id: String,
+    pub name: String,
+    pub sprite_type: SpriteType,
+    pub position: Position,
+    pub row: usize,
+    pub col: usize,
+    pub life: f64,
+    /// Life left to the Sprite armor, if any.
+    pub armor: f64,
+    pub visible: bool,
+    pub chilled: bool,
+    pub alpha: f64,
+    /// The scale the Sprite is drawn at.
+    pub scale: f64,
+    /// The part (0 - 1) of a recharging card left to recharge.
+    pub recharge: f64,
+    /// The swap cells the Sprite is drawn by, None for it's own cells.
+    pub swap_index: Option<usize>,
+    /// The text drawn over the Sprite, if any.
+    pub text: Option<String>,
+}
+
+impl From<&Sprite> for SpriteSnapshot {
+    fn from(sprite: &Sprite) -> Self {
+        SpriteSnapshot {
+            id: sprite.id.clone(),
+            name: sprite.name.clone(),
+            sprite_type: sprite.sprite_type.clone(),
+            position: sprite.position,
+            row: sprite.board_location.row,
+            col: sprite.board_location.col,
+            life: sprite.attack_state.life,
+            armor: sprite.attack_state.armor_life,
+            visible: sprite.visible,
+            chilled: sprite.drawing_state.chilled,
+            alpha: sprite.drawing_state.alpha,
+            scale: sprite.drawing_state.scale,
+            recharge: sprite.drawing_state.recharge,
+            swap_index: sprite.drawing_state.swap_index,
+            text: sprite
+                .text_overlay
+                .as_ref()
+                .map(|overlay| overlay.text.clone()),
+        }
+    }
+}
+
+/// Runs the game without a browser, using a manual clock, a headless painter and silent audio.
+/// Allows battles to be stepped frame by frame, e.g within plain `cargo test`.
+pub struct Simulation {
+    game: Game,
+    clock: ManualClock,
+}
+
+impl Simulation {
+    pub fn from_assets(assets_path: impl AsRef<Path>, seed: u64) -> Result<Self, EngineError> {
+        Self::with_storage(assets_path, seed, MemoryStorage::new())
+    }
+
+    /// Creates a simulation restoring the progress kept in the given storage, as a page reload would.
+    pub fn with_storage(
+        assets_path: impl AsRef<Path>,
+        seed: u64,
+        storage: MemoryStorage,
+    ) -> Result<Self, EngineError> {
+        let resources = ResourceLoader.load_from_dir(assets_path.as_ref())?;
+        let clock = ManualClock::new();
+
+        let mut game = Game::new(
+            Box::new(HeadlessPainter),
+            Box::new(clock.clone()),
+            Box::new(storage),
+            Box::new(SilentAudio),
+            Random::new(seed),
+        );
+
+        game.init(resources);
+
+        Ok(Simulation { game, clock })
+    }
+
+    /// Starts the given level as if it was selected from the home scene, recording it's replay.
+    pub fn start_level(&mut self, level_id: &str, seed: u64) {
+        self.game.start_level_by_id(level_id, seed);
+    }
+
+    /// Starts a battle of the given level right away, skipping the plants chooser and battle callout.
+    /// Battles started with the same seed are reproducible.
+    pub fn start_battle(&mut self, level: LevelData, seed: u64) {
+        let game = &mut self.game;
+
+        game.start_level(level, seed);
+
+        BattleScene::battle_callout(game);
+
+        game.toggle_game_behavior(false, &[BehaviorType::Scroll]);
+        game.remove_sprites_by_name(vec!["BattleCallout"]);
+
+        game.start_battle();
+    }
+
+    /// Plants the given plant at a board cell, returns whether the plant was placed.
+    pub fn plant(&mut self, name: &str, row: usize, col: usize) -> bool {
+        let game = &mut self.game;
+
+        let plant = Sprite::create_sprite(
+            name,
+            &ResourceKind::Plant,
+            &game.resources,
+            &mut game.random,
+        )
+        .remove(0);
+        let plant_id = plant.id.clone();
+
+        game.add_sprite(plant);
+        game.mouse_position = Self::cell_center(row, col);
+        game.plant_on_board(&plant_id);
+
+        game.sprites.iter().any(|sprite| sprite.id == plant_id)
+    }
+
+    /// Drops the shovel upon a board cell, digging it's top layer plant.
+    pub fn dig(&mut self, row: usize, col: usize) {
+        let game = &mut self.game;
+
+        game.mouse_position = Self::cell_center(row, col);
+        game.on_shovel_drag_end();
+    }
+
+    /// Places a walking zombie at a board cell, returns it's Sprite id.
+    pub fn spawn_zombie(&mut self, name: &str, row: usize, col: usize) -> String {
+        let game = &mut self.game;
+        let now = game.game_time.time;
+
+        let mut zombie = Sprite::create_sprite(
+            name,
+            &ResourceKind::Zombie,
+            &game.resources,
+            &mut game.random,
+        )
+        .remove(0);
+        let zombie_id = zombie.id.clone();
+
+        zombie.update_swap_cell(0);
+
+        let zombie_cell = DrawingState::get_active_cell(&zombie);
+        zombie.update_position(Board::get_board_placement(zombie_cell, row, col));
+        zombie.toggle_walking(true, now);
+
+        BehaviorManager::toggle_sprite_behaviors(
+            &zombie,
+            &[BehaviorType::Animate, BehaviorType::Collision],
+            true,
+            now,
+        );
+
+        game.add_sprite(zombie);
+
+        zombie_id
+    }
+
+    /// Dispatches a canvas mouse event, handled at the upcoming frame.
+    pub fn mouse_event(&mut self, event: GameMouseEvent, position: Position) {
+        self.game.handle_mouse_event(event, position);
+    }
+
+    /// Dispatches a keyboard event, handled at the upcoming frame.
+    pub fn key_event(&mut self, event: GameKeyEvent) {
+        self.game.handle_key_event(event);
+    }
+
+    /// Plays the given replay through, frame by frame.
+    pub fn play(&mut self, replay: Replay) {
+        self.game.play_replay(replay);
+
+        while self.game.is_replaying() {
+            self.step();
+        }
+    }
+
+    /// Plays the replay of the last finished level back, as the browser does by `?replay`.
+    /// Returns whether there was one.
+    pub fn play_last_replay(&mut self) -> bool {
+        let found = self.game.play_last_replay();
+
+        while self.game.is_replaying() {
+            self.step();
+        }
+
+        found
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.game.toggle_pause();
+    }
+
+    pub fn set_speed(&mut self, speed: f64) {
+        self.game.set_speed(speed);
+    }
+
+    pub fn set_volume(&mut self, volume: f64) {
+        self.game.set_volume(volume);
+    }
+
+    /// The scene music currently played, if any.
+    pub fn music(&self) -> Option<Music> {
+        self.game.audio.music()
+    }
+
+    /// Captures the running battle, None when there is no battle in progress.
+    pub fn snapshot(&self) -> Option<BattleSnapshot> {
+        self.game.snapshot()
+    }
+
+    pub fn restore(&mut self, snapshot: &BattleSnapshot) {
+        self.game.restore_snapshot(snapshot);
+    }
+
+    /// Continues the battle saved upon pausing, returns whether there was one.
+    pub fn continue_saved_battle(&mut self) -> bool {
+        self.game.continue_saved_battle()
+    }
+
+    /// Advances the game by a single frame.
+    pub fn step(&mut self) {
+        self.clock.advance(FRAME_DURATION);
+
+        self.game.run();
+    }
+
+    /// Advances the game by the given amount of game time (ms).
+    pub fn run_for(&mut self, duration: f64) {
+        let frames = (duration / FRAME_DURATION).ceil() as usize;
+
+        (0..frames).for_each(|_| self.step());
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.game.state.seed
+    }
+
+    pub fn game_time(&self) -> f64 {
+        self.game.game_time.time
+    }
+
+    /// The recording of the current level.
+    pub fn replay(&self) -> Option<Replay> {
+        self.game.replay.clone()
+    }
+
+    /// The level progress (0 - 1), as presented by the flag meter.
+    pub fn level_progress(&self) -> f64 {
+        WaveManager::progress(&self.game)
+    }
+
+    /// The id of the level currently played.
+    pub fn level_id(&self) -> Option<String> {
+        self.game
+            .state
+            .current_level
+            .as_ref()
+            .map(|level| level.name.clone())
+    }
+
+    /// The waves survived so far within the current survival run.
+    pub fn survival_score(&self) -> usize {
+        SurvivalManager::score(&self.game)
+    }
+
+    /// The current survival round, None outside of survival mode.
+    pub fn survival_round(&self) -> Option<usize> {
+        self.game
+            .state
+            .survival
+            .as_ref()
+            .map(|survival| survival.round)
+    }
+
+    pub fn completed_levels(&self) -> &[String] {
+        &self.game.save_data.completed_levels
+    }
+
+    pub fn save_data(&self) -> &SaveData {
+        &self.game.save_data
+    }
+
+    pub fn sun_score(&self) -> i32 {
+        self.game.state.sun_state.score
+    }
+
+    pub fn battle_stats(&self) -> &BattleStats {
+        &self.game.state.battle_stats
+    }
+
+    pub fn sprites(&self, sprite_type: &SpriteType) -> Vec<SpriteSnapshot> {
+        self.game
+            .sprites
+            .iter()
+            .filter(|sprite| &sprite.sprite_type == sprite_type)
+            .map(SpriteSnapshot::from)
+            .collect()
+    }
+
+    fn cell_center(row: usize, col: usize) -> Position {
+        let SpriteCell {
+            top,
+            left,
+            width,
+            height,
+        } = Board::get_cell_dimensions(row, col);
+
+        Position::new(top + height / 2.0, left + width / 2.0)
+    }
+}
@@ -0,0 +1,287 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::combat_manager::CombatState;
+use crate::engine::EngineError;
+use crate::features::GameFeatures;
+use crate::game::Game;
+use crate::model::{
+    AttackEffect, BattleStats, BehaviorType, LevelData, Position, SelectedSeed, SpriteType,
+};
+use crate::random::Random;
+use crate::recharge_manager::RechargeState;
+use crate::resource_loader::{ResourceKind, Resources};
+use crate::rules_manager::ConveyorState;
+use crate::scene::BattleScene;
+use crate::sprite::Sprite;
+use crate::storage::Storage;
+use crate::sun_manager::{SunManager, SunState};
+use crate::survival_manager::SurvivalState;
+use crate::wave_manager::WaveState;
+
+const SNAPSHOT_KEY: &str = "plants-vs-zombies/battle";
+
+/// A behavior runtime state, restored upon the matching behavior of a rebuilt Sprite.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedBehavior {
+    pub name: BehaviorType,
+    pub state: Value,
+}
+
+/// A data only representation of a Sprite.
+/// Images, cells and behaviors are rebuilt through `Resources` by the Sprite name and kind.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSprite {
+    pub id: String,
+    pub name: String,
+    pub kind: ResourceKind,
+    pub sprite_type: SpriteType,
+    pub order: usize,
+    pub position: Position,
+    pub origin_position: Position,
+    pub visible: bool,
+    pub sun_cost: usize,
+    pub life: f64,
+    pub damage: f64,
+    pub attack_enabled: bool,
+    pub effect: Option<AttackEffect>,
+    #[serde(default)]
+    pub last_bite: Option<f64>,
+    #[serde(default)]
+    pub armor_life: f64,
+    #[serde(default)]
+    pub passing: bool,
+    pub swap_index: Option<usize>,
+    pub active_cell: usize,
+    pub scale: f64,
+    pub offset: Position,
+    pub alpha: f64,
+    pub grayscale: bool,
+    pub chilled: bool,
+    pub behaviors: Vec<SavedBehavior>,
+}
+
+impl From<&Sprite> for SavedSprite {
+    fn from(sprite: &Sprite) -> Self {
+        let attack_state = &sprite.attack_state;
+        let drawing_state = &sprite.drawing_state;
+
+        SavedSprite {
+            id: sprite.id.clone(),
+            name: sprite.name.clone(),
+            kind: sprite.kind,
+            sprite_type: sprite.sprite_type.clone(),
+            order: sprite.order,
+            position: sprite.position,
+            origin_position: sprite.origin_position,
+            visible: sprite.visible,
+            sun_cost: sprite.sun_cost,
+            life: attack_state.life,
+            damage: attack_state.damage,
+            attack_enabled: attack_state.attack_enabled,
+            effect: attack_state.effect,
+            last_bite: attack_state.last_bite,
+            armor_life: attack_state.armor_life,
+            passing: attack_state.passing,
+            swap_index: drawing_state.swap_index,
+            active_cell: drawing_state.active_cell,
+            scale: drawing_state.scale,
+            offset: drawing_state.offset,
+            alpha: drawing_state.alpha,
+            grayscale: drawing_state.grayscale,
+            chilled: drawing_state.chilled,
+            behaviors: sprite
+                .mutable_behaviors()
+                .iter()
+                .map(|behavior| SavedBehavior {
+                    name: behavior.name(),
+                    state: behavior.save_state(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SavedSprite {
+    pub fn rebuild(&self, resources: &Resources, random: &mut Random) -> Sprite {
+        let mut sprite = Sprite::create_sprite(&self.name, &self.kind, resources, random).remove(0);
+
+        sprite.id = self.id.clone();
+        sprite.sprite_type = self.sprite_type.clone();
+        sprite.order = self.order;
+        sprite.origin_position = self.origin_position;
+        sprite.visible = self.visible;
+        sprite.sun_cost = self.sun_cost;
+
+        let attack_state = &mut sprite.attack_state;
+        attack_state.life = self.life;
+        attack_state.damage = self.damage;
+        attack_state.attack_enabled = self.attack_enabled;
+        attack_state.effect = self.effect;
+        attack_state.last_bite = self.last_bite;
+        attack_state.armor_life = self.armor_life;
+        attack_state.passing = self.passing;
+
+        let drawing_state = &mut sprite.drawing_state;
+        drawing_state.swap_index = self.swap_index;
+        drawing_state.active_cell = self.active_cell;
+        drawing_state.scale = self.scale;
+        drawing_state.offset = self.offset;
+        drawing_state.alpha = self.alpha;
+        drawing_state.grayscale = self.grayscale;
+        drawing_state.chilled = self.chilled;
+
+        // Placed over the restored cell
+        sprite.update_position(self.position);
+
+        // Behaviors added at runtime (e.g a dragged plant Drag) are not part of the Sprite data, thus skipped.
+        sprite.mutable_behaviors().iter_mut().for_each(|behavior| {
+            behavior.set_sprite_id(self.id.clone());
+
+            let saved = self
+                .behaviors
+                .iter()
+                .find(|saved| saved.name == behavior.name());
+
+            if let Some(saved) = saved {
+                behavior.restore_state(saved.state.clone(), &sprite);
+            }
+        });
+
+        sprite
+    }
+}
+
+/// A running battle, captured in between frames.
+/// Restoring it continues the battle right where it was captured, as the game time and randomness are kept as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BattleSnapshot {
+    pub level: LevelData,
+    pub seed: u64,
+    pub random: Random,
+    /// The game time the battle was captured at.
+    pub time: f64,
+    pub sun_state: SunState,
+    pub wave_state: WaveState,
+    pub selected_seeds: Vec<SelectedSeed>,
+    pub lost_lawn_cleaners: usize,
+    #[serde(default)]
+    pub survival: Option<SurvivalState>,
+    #[serde(default)]
+    pub conveyor_state: ConveyorState,
+    #[serde(default)]
+    pub recharge_state: RechargeState,
+    #[serde(default)]
+    pub combat_state: CombatState,
+    #[serde(default)]
+    pub battle_stats: BattleStats,
+    pub sprites: Vec<SavedSprite>,
+}
+
+impl BattleSnapshot {
+    /// Captures the running battle, None when there is no battle in progress.
+    pub fn capture(game: &Game) -> Option<BattleSnapshot> {
+        let level = game.state.current_level.clone()?;
+
+        if !GameFeatures::should_spawn_waves() {
+            return None;
+        }
+
+        // A plant being dragged isn't placed yet, it's card can simply be picked again.
+        let sprites = game
+            .sprites
+            .iter()
+            .filter(|sprite| !Self::is_dragged_plant(sprite))
+            .map(SavedSprite::from)
+            .collect();
+
+        Some(BattleSnapshot {
+            level,
+            seed: game.state.seed,
+            random: game.random.clone(),
+            time: game.game_time.time,
+            sun_state: game.state.sun_state.clone(),
+            wave_state: game.state.wave_state.clone(),
+            selected_seeds: game.state.selected_seeds.clone(),
+            lost_lawn_cleaners: game.state.lost_lawn_cleaners,
+            survival: game.state.survival.clone(),
+            conveyor_state: game.state.conveyor_state.clone(),
+            recharge_state: game.state.recharge_state.clone(),
+            combat_state: game.state.combat_state.clone(),
+            battle_stats: game.state.battle_stats.clone(),
+            sprites,
+        })
+    }
+
+    /// Replaces the current game with the captured battle.
+    pub fn restore(&self, game: &mut Game) {
+        game.reset_state();
+
+        game.game_time.time = self.time;
+        game.game_time.last_timestamp = self.time;
+        game.game_time.set_paused(false);
+
+        game.sprites = self
+            .sprites
+            .iter()
+            .map(|saved| saved.rebuild(&game.resources, &mut game.random))
+            .collect();
+
+        game.state.seed = self.seed;
+        game.state.current_level = Some(self.level.clone());
+        game.state.sun_state = self.sun_state.clone();
+        game.state.wave_state = self.wave_state.clone();
+        game.state.selected_seeds = self.selected_seeds.clone();
+        game.state.lost_lawn_cleaners = self.lost_lawn_cleaners;
+        game.state.survival = self.survival.clone();
+        game.state.conveyor_state = self.conveyor_state.clone();
+        game.state.recharge_state = self.recharge_state.clone();
+        game.state.combat_state = self.combat_state.clone();
+        game.state.battle_stats = self.battle_stats.clone();
+
+        GameFeatures::enable_board_lines(true);
+        GameFeatures::enable_update_sun_score(true);
+        GameFeatures::enable_spawn_waves(true);
+        SunManager::start_battle(game);
+
+        // Flag meter fill cell is sized by the level progress, rather than it's data.
+        if game
+            .sprites
+            .iter()
+            .any(|sprite| sprite.name == "FlagMeterFull")
+        {
+            BattleScene::update_flag_meter(game);
+        }
+
+        // Randomness is restored last, as rebuilding sprites draws their ids.
+        game.random = self.random.clone();
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, EngineError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn load(storage: &dyn Storage) -> Option<BattleSnapshot> {
+        Self::from_json(&storage.get(SNAPSHOT_KEY)?).ok()
+    }
+
+    pub fn store(&self, storage: &mut dyn Storage) -> Result<(), EngineError> {
+        storage.set(SNAPSHOT_KEY, &self.to_json())
+    }
+
+    pub fn clear(storage: &mut dyn Storage) {
+        storage.remove(SNAPSHOT_KEY);
+    }
+
+    fn is_dragged_plant(sprite: &Sprite) -> bool {
+        sprite.sprite_type == SpriteType::Plant
+            && sprite
+                .mutable_behaviors()
+                .iter()
+                .any(|behavior| behavior.name() == BehaviorType::Drag && behavior.is_running())
+    }
+}
@@ -1,20 +1,35 @@
-use crate::model::AttackEffect;
+use crate::constants::SLOW_FACTOR;
+use crate::model::{ArmorData, AttackEffect, EatingData, Trajectory};
 
 #[derive(Debug, Default)]
 pub struct AttackState {
     pub life: f64,
+    /// The life the Sprite starts with, damage stages are relative to it.
+    pub max_life: f64,
     pub damage: f64,
     pub attack_enabled: bool,
     pub effect: Option<AttackEffect>,
+    pub eating: Option<EatingData>,
+    /// Game time of the last bite, None while not eating.
+    pub last_bite: Option<f64>,
+    pub damage_stages: Vec<f64>,
+    pub armor: Option<ArmorData>,
+    /// Life left to the armor, which is broken once it runs out.
+    pub armor_life: f64,
+    pub trajectory: Trajectory,
+    /// Set while the Sprite passes over (or under) the others, neither attacking nor being hit.
+    pub passing: bool,
 }
 
 impl AttackState {
     pub fn new(life: f64, damage: f64, effect: Option<AttackEffect>) -> Self {
         AttackState {
             life,
+            max_life: life,
             damage,
             effect,
             attack_enabled: true,
+            ..Default::default()
         }
     }
 
@@ -25,12 +40,84 @@ impl AttackState {
         }
     }
 
+    /// The damage of an attack at the given time, eating Sprites deal the damage of the bites due by then.
+    pub fn get_attack_damage(&self, now: f64, slowed: bool) -> f64 {
+        match self.eating {
+            Some(eating) if self.attack_enabled => {
+                eating.bite_damage() * self.due_bites(&eating, now, slowed)
+            }
+            _ => self.get_damage(),
+        }
+    }
+
+    /// Takes the bites due by the given time, where the first bite is taken right away.
+    pub fn bite(&mut self, now: f64, slowed: bool) {
+        if let Some(eating) = self.eating {
+            let bites = self.due_bites(&eating, now, slowed);
+
+            self.last_bite = match self.last_bite {
+                Some(last_bite) => Some(last_bite + bites * Self::bite_rate(&eating, slowed)),
+                None => Some(now),
+            };
+        }
+    }
+
+    pub fn stop_eating(&mut self) {
+        self.last_bite = None;
+    }
+
+    fn due_bites(&self, eating: &EatingData, now: f64, slowed: bool) -> f64 {
+        match self.last_bite {
+            Some(last_bite) => ((now - last_bite) / Self::bite_rate(eating, slowed)).floor(),
+            None => 1.0,
+        }
+    }
+
+    /// Chilled Sprites eat slower.
+    fn bite_rate(eating: &EatingData, slowed: bool) -> f64 {
+        match slowed {
+            true => eating.bite_rate / SLOW_FACTOR,
+            false => eating.bite_rate,
+        }
+    }
+
+    /// The damage stage by the remaining life, 0 while none of the stages is reached.
+    pub fn damage_stage(&self) -> usize {
+        self.damage_stages
+            .iter()
+            .filter(|stage| self.life <= self.max_life * *stage)
+            .count()
+    }
+
     pub fn mutate_damage(&mut self, damage: f64) {
         self.damage += damage
     }
 
-    pub fn take_damage(&mut self, damage: f64) {
-        self.life -= damage;
+    /// Takes the damage of an attack, where the armor blocking it takes as much of it as it can.
+    pub fn take_damage(&mut self, damage: f64, trajectory: Trajectory) {
+        let body_damage = self.body_damage(damage, trajectory);
+
+        self.armor_life -= damage - body_damage;
+        self.life -= body_damage;
+    }
+
+    /// The part of an attack damage getting through the armor.
+    pub fn body_damage(&self, damage: f64, trajectory: Trajectory) -> f64 {
+        match &self.armor {
+            Some(armor) if self.is_armored() && armor.kind.blocks(trajectory) => {
+                (damage - self.armor_life).max(0.0)
+            }
+            _ => damage,
+        }
+    }
+
+    pub fn set_armor(&mut self, armor: Option<ArmorData>) {
+        self.armor_life = armor.as_ref().map_or(0.0, |armor| armor.life);
+        self.armor = armor;
+    }
+
+    pub fn is_armored(&self) -> bool {
+        self.armor.is_some() && self.armor_life > 0.0
     }
 
     pub fn is_dead(&self) -> bool {
@@ -1,22 +1,21 @@
 use std::cell::{RefCell, RefMut};
 use std::rc::Weak;
 
-use js_sys::Math;
 use web_sys::HtmlImageElement;
 
 use crate::board::{Board, BoardLocation};
 use crate::location_builder::LocationBuilder;
 use crate::model::{
-    AttackEffect, BehaviorData, BehaviorType, CollisionMargin, Dimensions, Position, SpriteCell,
-    SpriteData, SpriteType, TextOverlayData,
+    AttackEffect, BehaviorData, BehaviorType, CollisionMargin, Dimensions, MovementKind, PlantLayer,
+    Position, SpriteCell, SpriteData, SpriteType, TextOverlayData,
 };
+use crate::random::Random;
 use crate::resource_loader::{Resource, ResourceKind, Resources};
 use crate::sprite::attack_state::AttackState;
-use crate::sprite::behavior::{Animate, Behavior, BehaviorManager, Collision};
+use crate::sprite::behavior::{Animate, Behavior, BehaviorManager, Collision, Leap, Walk};
 use crate::sprite::drawing_state::DrawingState;
 use crate::sprite::text_overlay::TextOverlay;
 use crate::sprite::{Outline, SpriteMutation};
-use crate::web_utils::window_time;
 
 pub struct Sprite {
     pub id: String,
@@ -33,10 +32,19 @@ pub struct Sprite {
     pub sun_cost: usize,
     pub text_overlay: Option<TextOverlay>,
     pub sprite_type: SpriteType,
+    /// The resource kind the Sprite was created from.
+    pub kind: ResourceKind,
     pub visible: bool,
+    /// The board cell layer of a plant.
+    pub layer: PlantLayer,
+    /// Special movement of a zombie.
+    pub movement: Option<MovementKind>,
+    /// The plant card a dragged plant was taken from.
+    pub source_card: Option<String>,
 }
 
 impl Sprite {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: &str,
         order: usize,
@@ -46,7 +54,7 @@ impl Sprite {
         swap_cells: Vec<Vec<SpriteCell>>,
         image: Option<Weak<HtmlImageElement>>,
         scale: f64,
-        behaviors: &Vec<BehaviorData>,
+        behaviors: &[BehaviorData],
         exact_outlines: bool,
         text_overlay_data: &Option<TextOverlayData>,
         kind: ResourceKind,
@@ -54,8 +62,9 @@ impl Sprite {
         damage: f64,
         attack_effect: Option<AttackEffect>,
         sun_cost: usize,
+        random: &mut Random,
     ) -> Sprite {
-        let id = uid(name);
+        let id = uid(name, random);
         let sprite_type = SpriteType::from_kind(&kind);
 
         let sprite_behaviors = RefCell::new(
@@ -79,13 +88,15 @@ impl Sprite {
             behaviors: sprite_behaviors,
             text_overlay: None,
             sprite_type,
+            kind,
             sun_cost,
             visible: true,
+            layer: PlantLayer::default(),
+            movement: None,
+            source_card: None,
         };
 
-        sprite.text_overlay = text_overlay_data
-            .as_ref()
-            .map(|data| TextOverlay::new(data, &sprite));
+        sprite.text_overlay = text_overlay_data.as_ref().map(TextOverlay::new);
 
         sprite.update_board_location();
         sprite.update_outlines(exact_outlines);
@@ -143,15 +154,33 @@ impl Sprite {
         self.update_position(swapped_position);
     }
 
+    /// Swaps into the cells of the damage stage reached by the remaining life.
+    fn update_damage_stage(&mut self) {
+        if self.attack_state.damage_stages.is_empty() || self.attack_state.is_dead() {
+            return;
+        }
+
+        let swap_index = self.attack_state.damage_stage() as i32 - 1;
+        let current_index = self
+            .drawing_state
+            .swap_index
+            .map_or(-1, |swap_index| swap_index as i32);
+
+        if swap_index != current_index {
+            self.update_swap_cell(swap_index);
+        }
+    }
+
     pub fn create_sprites(
         sprite_names: Vec<&str>,
         kind: &ResourceKind,
         resources: &Resources,
+        random: &mut Random,
     ) -> Vec<Sprite> {
-        return sprite_names
+        sprite_names
             .iter()
-            .flat_map(|sprite_name| Sprite::create_sprite(sprite_name, kind, resources))
-            .collect();
+            .flat_map(|sprite_name| Sprite::create_sprite(sprite_name, kind, resources, random))
+            .collect()
     }
 
     /// Creates a Sprite by a given name and kind.
@@ -159,6 +188,7 @@ impl Sprite {
         sprite_name: &str,
         kind: &ResourceKind,
         resources: &Resources,
+        random: &mut Random,
     ) -> Vec<Sprite> {
         let Resource { data, .. } = resources.get_resource(sprite_name, kind);
 
@@ -175,9 +205,30 @@ impl Sprite {
             sun_cost,
             swap_cells,
             attack_effect,
+            combat,
+            layer,
+            eating,
+            damage_stages,
+            armor,
+            trajectory,
+            movement,
             ..
         } = data;
 
+        // Plants shoot at the rate of their combat data.
+        let behaviors = behaviors
+            .into_iter()
+            .map(
+                |behavior| match (&combat, BehaviorType::from_string(&behavior.name)) {
+                    (Some(combat), BehaviorType::Interval) => BehaviorData {
+                        interval: Some(combat.fire_rate),
+                        ..behavior
+                    },
+                    _ => behavior,
+                },
+            )
+            .collect::<Vec<BehaviorData>>();
+
         // Map each position into it's own Sprite.
         position
             .iter()
@@ -189,7 +240,7 @@ impl Sprite {
                     .map(|cell_name| resources.get_cell(cell_name, kind))
                     .collect::<Vec<Vec<SpriteCell>>>();
 
-                Sprite::new(
+                let mut sprite = Sprite::new(
                     sprite_name,
                     order,
                     *position,
@@ -206,12 +257,27 @@ impl Sprite {
                     damage,
                     attack_effect,
                     sun_cost,
-                )
+                    random,
+                );
+
+                sprite.layer = layer;
+                sprite.attack_state.eating = eating;
+                sprite.attack_state.damage_stages = damage_stages.clone();
+                sprite.attack_state.set_armor(armor.clone());
+                sprite.attack_state.trajectory = trajectory;
+                sprite.movement = movement;
+
+                // Zombies leaping over the plants they reach don't eat them ahead.
+                if movement.is_some_and(|movement| movement.on_contact()) {
+                    sprite.attack_state.attack_enabled = false;
+                }
+
+                sprite
             })
             .collect()
     }
 
-    pub fn apply_mutation(&mut self, mutations: Vec<SpriteMutation>) {
+    pub fn apply_mutation(&mut self, mutations: Vec<SpriteMutation>, now: f64) {
         mutations.iter().for_each(|mutation| {
             if let Some(hovered) = mutation.hovered {
                 self.drawing_state.hover(hovered);
@@ -242,7 +308,10 @@ impl Sprite {
             }
 
             if let Some(damage) = mutation.taken_damage {
-                self.attack_state.take_damage(damage);
+                let trajectory = mutation.trajectory.unwrap_or_default();
+
+                self.attack_state.take_damage(damage, trajectory);
+                self.update_damage_stage();
             }
 
             if let Some(swap_index) = mutation.swap {
@@ -250,12 +319,12 @@ impl Sprite {
             }
 
             if let Some(walking) = mutation.walking {
-                self.toggle_walking(walking);
+                self.toggle_walking(walking, now);
             }
 
             if let Some(mute) = mutation.mute {
                 self.attack_state.mute(!mute);
-                self.toggle_walking(!mute);
+                self.toggle_walking(!mute, now);
             }
 
             if mutation.stop_animate.is_some() {
@@ -266,6 +335,34 @@ impl Sprite {
 
                 animate.set_max_cycles(1);
             }
+
+            if let Some(slowed) = mutation.slowed {
+                self.drawing_state.chilled = slowed;
+
+                self.mutable_behaviors()
+                    .iter_mut()
+                    .for_each(|behavior| behavior.slow_down(slowed));
+            }
+
+            if let Some(effect) = mutation.attack_effect {
+                self.attack_state.effect = Some(effect);
+            }
+
+            if let Some((offset, landing_swap)) = mutation.leap {
+                self.leap(offset, landing_swap, now);
+            }
+
+            if let Some(passing) = mutation.passing {
+                self.attack_state.passing = passing;
+            }
+
+            if mutation.turn_around.is_some() {
+                BehaviorManager::get_sprite_behavior(self, BehaviorType::Walk)
+                    .as_any()
+                    .downcast_mut::<Walk>()
+                    .unwrap()
+                    .turn_around();
+            }
         });
     }
 
@@ -289,11 +386,25 @@ impl Sprite {
         })
     }
 
-    pub fn toggle_walking(&mut self, walking: bool) {
-        BehaviorManager::toggle_sprite_behaviors(self, &[BehaviorType::Walk], walking, window_time())
+    pub fn toggle_walking(&mut self, walking: bool, now: f64) {
+        BehaviorManager::toggle_sprite_behaviors(self, &[BehaviorType::Walk], walking, now)
+    }
+
+    /// Leaps by the given offset, passing the other Sprites by until landed.
+    fn leap(&mut self, offset: Position, landing_swap: i32, now: f64) {
+        let leap = BehaviorManager::get_sprite_behavior(self, BehaviorType::Leap)
+            .as_any()
+            .downcast_mut::<Leap>()
+            .unwrap();
+
+        leap.set_offset(offset, landing_swap);
+        leap.toggle(true, now);
+
+        self.attack_state.passing = true;
+        self.toggle_walking(false, now);
     }
 }
 
-pub fn uid(prefix: &str) -> String {
-    format!("{}_{}", prefix, &Math::random().to_string()[2..12])
+pub fn uid(prefix: &str, random: &mut Random) -> String {
+    format!("{}_{:010}", prefix, random.next_u64() % 10_000_000_000)
 }
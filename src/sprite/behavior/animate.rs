@@ -1,12 +1,14 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
+use crate::constants::SLOW_FACTOR;
 use crate::model::{BehaviorType, Callback, GameInteraction, Position};
 use crate::sprite::{Sprite, SpriteMutation};
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Animate {
     last_tick: f64,
     rate: f64,
@@ -14,6 +16,7 @@ pub struct Animate {
     max_cycles: usize,
     callback: Option<Callback>,
     callback_delay: f64,
+    slowed: bool,
 }
 
 impl Animate {
@@ -36,6 +39,13 @@ impl Animate {
         self.finished_cycles = 0;
         self.max_cycles = max_cycles;
     }
+
+    fn current_rate(&self) -> f64 {
+        match self.slowed {
+            true => self.rate / SLOW_FACTOR,
+            false => self.rate,
+        }
+    }
 }
 
 impl Behavior for Animate {
@@ -43,6 +53,18 @@ impl Behavior for Animate {
         BehaviorType::Animate
     }
 
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
+    fn slow_down(&mut self, slowed: bool) {
+        self.slowed = slowed;
+    }
+
     fn get_interaction(&self) -> Option<GameInteraction> {
         if !self.interaction_active || self.callback.is_none() {
             return None;
@@ -64,12 +86,11 @@ impl Behavior for Animate {
         now: f64,
         _last_frame: f64,
         _mouse: &Position,
-        _context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         let infinite = self.max_cycles == 0;
 
         let finished = !infinite && self.finished_cycles == self.max_cycles;
-        let should_animate = now - self.last_tick >= self.rate;
+        let should_animate = now - self.last_tick >= self.current_rate();
 
         if finished {
             let execute_callback = now - self.last_tick > self.callback_delay;
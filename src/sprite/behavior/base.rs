@@ -1,6 +1,8 @@
 use std::any::Any;
 
-use web_sys::CanvasRenderingContext2d;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
 
 use crate::model::{BehaviorType, GameInteraction, Position};
 use crate::sprite::{Sprite, SpriteMutation};
@@ -30,6 +32,21 @@ pub trait Behavior: BehaviorState {
         None
     }
 
+    /// Whether the behavior keeps running while the game is paused.
+    fn runs_while_paused(&self) -> bool {
+        false
+    }
+
+    /// Slows the behavior down, or restores it's normal speed.
+    fn slow_down(&mut self, _slowed: bool) {}
+
+    /// Captures the behavior runtime state, so it can be restored upon a rebuilt Sprite.
+    fn save_state(&self) -> Value {
+        Value::Null
+    }
+
+    fn restore_state(&mut self, _state: Value, _sprite: &Sprite) {}
+
     fn animation_rate(&mut self, now: f64, last_frame: f64) -> f64 {
         (now - last_frame) / 1000.0
     }
@@ -53,6 +70,17 @@ pub trait Behavior: BehaviorState {
         now: f64,
         last_frame: f64,
         mouse: &Position,
-        context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation>;
 }
+
+/// Serializes a behavior (or a behavior part) state, see `Behavior::save_state`.
+pub fn save_state<T: Serialize>(state: &T) -> Value {
+    serde_json::to_value(state).unwrap_or_default()
+}
+
+/// Restores a state saved by `save_state`, an unreadable state is ignored.
+pub fn restore_state<T: DeserializeOwned>(target: &mut T, state: Value) {
+    if let Ok(restored) = serde_json::from_value(state) {
+        *target = restored;
+    }
+}
@@ -1,21 +1,23 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::model::{BehaviorType, Callback, GameInteraction, Position};
-use crate::painter::Painter;
-use crate::sprite::{Sprite, SpriteMutation};
+use crate::sprite::{Outline, Sprite, SpriteMutation};
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Click {
     pub callback: Callback,
+    pub while_paused: bool,
 }
 
 impl Click {
-    pub fn new(callback: Callback) -> Click {
+    pub fn new(callback: Callback, while_paused: bool) -> Click {
         Click {
             callback,
+            while_paused,
             ..Default::default()
         }
     }
@@ -26,6 +28,18 @@ impl Behavior for Click {
         BehaviorType::Click
     }
 
+    fn runs_while_paused(&self) -> bool {
+        self.while_paused
+    }
+
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
     fn get_interaction(&self) -> Option<GameInteraction> {
         if self.interaction_active {
             return Some(GameInteraction::SpriteClick(
@@ -43,11 +57,10 @@ impl Behavior for Click {
         now: f64,
         _last_frame: f64,
         mouse: &Position,
-        context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         self.stop(now);
 
-        let clicked = Painter::in_path(&sprite.outlines, mouse, context);
+        let clicked = Outline::in_path(&sprite.outlines, mouse);
 
         if clicked {
             self.interaction_active = true;
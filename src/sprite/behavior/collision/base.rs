@@ -1,14 +1,16 @@
-use crate::model::{AttackEffect, Callback};
+use serde_json::Value;
+
+use crate::model::{AttackEffect, Callback, Trajectory};
 use crate::sprite::{CollisionState, Sprite, SpriteMutation};
 
 pub type DelayedMutation = (Option<SpriteMutation>, f64);
 
 pub trait CollisionHandler {
-    fn tick(&mut self) -> Option<SpriteMutation> {
+    fn tick(&mut self, _sprite: &Sprite, _now: f64) -> Option<SpriteMutation> {
         None
     }
 
-    fn on_attack(&mut self) -> SpriteMutation {
+    fn on_attack(&mut self, _now: f64) -> SpriteMutation {
         SpriteMutation::new()
     }
 
@@ -16,15 +18,23 @@ pub trait CollisionHandler {
         (None, 0.0)
     }
 
-    fn on_hit(&mut self, damage: f64) -> SpriteMutation {
-        SpriteMutation::new().take_damage(damage)
+    fn on_hit(
+        &mut self,
+        damage: f64,
+        _effect: Option<AttackEffect>,
+        trajectory: Trajectory,
+        _now: f64,
+    ) -> SpriteMutation {
+        SpriteMutation::new()
+            .take_damage(damage)
+            .trajectory(trajectory)
     }
 
     fn on_after_hit(&mut self) -> DelayedMutation {
         (None, 0.0)
     }
 
-    fn on_die(&mut self, _damage: f64) -> SpriteMutation {
+    fn on_die(&mut self, _damage: f64, _trajectory: Trajectory) -> SpriteMutation {
         SpriteMutation::new().hide(true)
     }
 
@@ -37,6 +47,7 @@ pub trait CollisionHandler {
         _sprite: &Sprite,
         _state: &CollisionState,
         _prev_state: &CollisionState,
+        _now: f64,
     ) -> Option<SpriteMutation> {
         None
     }
@@ -44,4 +55,15 @@ pub trait CollisionHandler {
     fn get_interaction_callback(&mut self) -> Option<Callback> {
         None
     }
+
+    /// Whether the Sprite is done colliding once it's interaction callback is raised.
+    fn is_done(&self) -> bool {
+        true
+    }
+
+    fn save_state(&self) -> Value {
+        Value::Null
+    }
+
+    fn restore_state(&mut self, _state: Value) {}
 }
@@ -1,9 +1,13 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
 use crate::model::AttackEffect;
+use crate::sprite::behavior::base::{restore_state, save_state};
 use crate::sprite::behavior::collision::base::{CollisionHandler, DelayedMutation};
 use crate::sprite::behavior::collision::bullet::BulletState::Flying;
 use crate::sprite::SpriteMutation;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum BulletState {
     Hit,
     FireBullet,
@@ -16,6 +20,7 @@ impl BulletState {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct BulletCollisionHandler {
     state: BulletState,
 }
@@ -27,7 +32,7 @@ impl BulletCollisionHandler {
 }
 
 impl CollisionHandler for BulletCollisionHandler {
-    fn on_attack(&mut self) -> SpriteMutation {
+    fn on_attack(&mut self, _now: f64) -> SpriteMutation {
         self.state = BulletState::Hit;
         SpriteMutation::new().swap(self.state.index()).mute(true)
     }
@@ -40,11 +45,21 @@ impl CollisionHandler for BulletCollisionHandler {
         if effect == AttackEffect::TurnIntoFireBullet && self.state != BulletState::FireBullet {
             self.state = BulletState::FireBullet;
 
+            // Fire bullets thaw chilled zombies, rather than chilling them.
             return SpriteMutation::new()
                 .swap(self.state.index())
-                .increase_damage(15.0);
+                .increase_damage(15.0)
+                .attack_effect(AttackEffect::Thaw);
         }
 
         SpriteMutation::new()
     }
+
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value) {
+        restore_state(self, state);
+    }
 }
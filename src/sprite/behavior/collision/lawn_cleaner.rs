@@ -1,7 +1,12 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
 use crate::model::Callback;
+use crate::sprite::behavior::base::{restore_state, save_state};
 use crate::sprite::behavior::collision::base::CollisionHandler;
 use crate::sprite::{CollisionState, Sprite, SpriteMutation};
 
+#[derive(Serialize, Deserialize)]
 pub struct LawnCleanerCollisionHandler {
     collided: bool,
 }
@@ -18,6 +23,7 @@ impl CollisionHandler for LawnCleanerCollisionHandler {
         _sprite: &Sprite,
         state: &CollisionState,
         _prev_state: &CollisionState,
+        _now: f64,
     ) -> Option<SpriteMutation> {
         if state == &CollisionState::Attacking {
             self.collided = true;
@@ -35,4 +41,12 @@ impl CollisionHandler for LawnCleanerCollisionHandler {
 
         None
     }
+
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value) {
+        restore_state(self, state);
+    }
 }
@@ -5,11 +5,13 @@ mod plant;
 mod zombie;
 
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::model::{
     AttackEffect, BehaviorType, Callback, CollisionMargin, GameInteraction, Position, SpriteType,
+    Trajectory,
 };
 use crate::sprite::behavior::collision::base::{CollisionHandler, DelayedMutation};
 use crate::sprite::behavior::collision::bullet::BulletCollisionHandler;
@@ -19,30 +21,27 @@ use crate::sprite::behavior::collision::zombie::ZombieCollisionHandler;
 use crate::sprite::{Sprite, SpriteMutation};
 use crate::timers::Timer;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CollisionState {
+    #[default]
     None,
     Attacking,
-    TakingDamage(f64),
+    TakingDamage(f64, Option<AttackEffect>, Trajectory),
     ApplyEffect(AttackEffect),
-}
-
-impl Default for CollisionState {
-    fn default() -> Self {
-        CollisionState::None
-    }
+    /// Passing the others by, e.g leaping over them.
+    Passing,
 }
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Collision {
-    pub name: BehaviorType,
     pub margin: CollisionMargin,
     pub state: CollisionState,
     pub prev_state: CollisionState,
-    pub last_collided: f64,
 
     delayed_mutation_timer: Timer,
+    /// Handler state is saved alongside, as it's type is only known by the Sprite.
+    #[serde(skip)]
     handler: Option<Box<dyn CollisionHandler>>,
     delayed_mutation: Option<SpriteMutation>,
     interaction_callback: Option<Callback>,
@@ -52,7 +51,6 @@ impl Collision {
     pub fn new(margin: CollisionMargin) -> Collision {
         Collision {
             margin,
-            name: BehaviorType::Collision,
             state: CollisionState::None,
             prev_state: CollisionState::None,
             delayed_mutation: None,
@@ -61,13 +59,14 @@ impl Collision {
         }
     }
 
-    fn set_collision_handler(&mut self, sprite_type: &SpriteType) {
+    fn set_collision_handler(&mut self, sprite: &Sprite) {
         if self.handler.is_some() {
             return;
         }
 
+        let sprite_type = &sprite.sprite_type;
         let handler: Box<dyn CollisionHandler> = match sprite_type {
-            SpriteType::Zombie => Box::new(ZombieCollisionHandler::new()),
+            SpriteType::Zombie => Box::new(ZombieCollisionHandler::new(sprite)),
             SpriteType::Plant => Box::new(PlantCollisionHandler {}),
             SpriteType::Bullet => Box::new(BulletCollisionHandler::new()),
             SpriteType::LawnCleaner => Box::new(LawnCleanerCollisionHandler::new()),
@@ -79,12 +78,12 @@ impl Collision {
         self.handler = Some(handler);
     }
 
-    fn set_delayed_mutation(&mut self, delayed_mutation: DelayedMutation) {
+    fn set_delayed_mutation(&mut self, delayed_mutation: DelayedMutation, now: f64) {
         let (mutation, delay) = delayed_mutation;
         if self.delayed_mutation.is_none() {
             if let Some(mutation) = mutation {
                 self.delayed_mutation_timer.set_elapsed(delay);
-                self.delayed_mutation_timer.start();
+                self.delayed_mutation_timer.start(now);
 
                 self.delayed_mutation = Some(mutation);
             }
@@ -97,15 +96,35 @@ impl Behavior for Collision {
         BehaviorType::Collision
     }
 
+    fn save_state(&self) -> Value {
+        let mut state = save_state(self);
+
+        if let Some(handler) = &self.handler {
+            state["handler"] = handler.save_state();
+        }
+
+        state
+    }
+
+    fn restore_state(&mut self, state: Value, sprite: &Sprite) {
+        let handler_state = state.get("handler").cloned();
+
+        restore_state(self, state);
+
+        if let Some(handler_state) = handler_state {
+            self.set_collision_handler(sprite);
+            self.handler.as_mut().unwrap().restore_state(handler_state);
+        }
+    }
+
     fn get_interaction(&self) -> Option<GameInteraction> {
-        if self.interaction_active && self.interaction_callback.is_some() {
-            return Some(GameInteraction::SpriteClick(
-                self.interaction_callback.unwrap(),
+        match self.interaction_callback {
+            Some(callback) if self.interaction_active => Some(GameInteraction::SpriteClick(
+                callback,
                 self.sprite_id.clone(),
-            ));
+            )),
+            _ => None,
         }
-
-        None
     }
 
     /// Collision state is managed by the BattleManager,
@@ -118,19 +137,16 @@ impl Behavior for Collision {
         now: f64,
         _last_frame: f64,
         _mouse: &Position,
-        _context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         let mut mutation: Option<SpriteMutation> = None;
         let mut delayed_mutation: DelayedMutation = (None, 0.0);
-        let current_timer_time = self.delayed_mutation_timer.get_current_time();
 
         // Ensures CollisionHandler is set
-        self.set_collision_handler(&sprite.sprite_type);
+        self.set_collision_handler(sprite);
         let collision_handler = self.handler.as_mut().unwrap();
 
         // Handles delayed mutation set by the handler if any
-        if self.delayed_mutation.is_some() && self.delayed_mutation_timer.expired(current_timer_time)
-        {
+        if self.delayed_mutation.is_some() && self.delayed_mutation_timer.expired(now) {
             let mutation = self.delayed_mutation.clone();
             self.delayed_mutation = None;
 
@@ -138,15 +154,19 @@ impl Behavior for Collision {
         }
 
         // Handles `tick` phase mutation if set
-        let tick_mutation = collision_handler.tick();
+        let tick_mutation = collision_handler.tick(sprite, now);
         if tick_mutation.is_some() {
             return tick_mutation;
         }
 
         // Handle Collision state changes hooks
         if self.prev_state != self.state {
-            let state_change_mutation =
-                collision_handler.on_collision_state_change(sprite, &self.state, &self.prev_state);
+            let state_change_mutation = collision_handler.on_collision_state_change(
+                sprite,
+                &self.state,
+                &self.prev_state,
+                now,
+            );
 
             if state_change_mutation.is_some() {
                 return state_change_mutation;
@@ -155,21 +175,23 @@ impl Behavior for Collision {
 
         // Handle Collision hooks
         match self.state {
-            CollisionState::None => {}
+            CollisionState::None | CollisionState::Passing => {}
             CollisionState::Attacking => {
-                mutation = Some(collision_handler.on_attack());
+                mutation = Some(collision_handler.on_attack(now));
 
                 delayed_mutation = collision_handler.on_after_attack();
             }
-            CollisionState::TakingDamage(damage) => {
+            CollisionState::TakingDamage(damage, effect, trajectory) => {
                 if damage <= 0.0 {
                     return None;
                 }
 
-                if sprite.attack_state.life - damage <= 0.0 {
-                    mutation = Some(collision_handler.on_die(damage));
+                let body_damage = sprite.attack_state.body_damage(damage, trajectory);
+
+                if sprite.attack_state.life - body_damage <= 0.0 {
+                    mutation = Some(collision_handler.on_die(damage, trajectory));
                 } else {
-                    mutation = Some(collision_handler.on_hit(damage));
+                    mutation = Some(collision_handler.on_hit(damage, effect, trajectory, now));
 
                     delayed_mutation = collision_handler.on_after_hit();
                 }
@@ -185,11 +207,13 @@ impl Behavior for Collision {
             self.interaction_active = true;
             self.interaction_callback = interaction_callback;
 
-            self.stop(now);
+            if collision_handler.is_done() {
+                self.stop(now);
+            }
         }
 
         // Sets delayed mutation
-        self.set_delayed_mutation(delayed_mutation);
+        self.set_delayed_mutation(delayed_mutation, now);
 
         // Persisting current state
         self.prev_state = self.state.clone();
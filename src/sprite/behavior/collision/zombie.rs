@@ -1,17 +1,31 @@
-use crate::model::Callback;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::board::{COL_X_COORD, ROW_Y_COORD};
+use crate::constants::{SLOW_DURATION, VAULT_DISTANCE};
+use crate::location_builder::LocationBuilder;
+use crate::model::{
+    ArmorData, ArmorKind, AttackEffect, Callback, MovementKind, Position, SoundEffect, Trajectory,
+};
+use crate::sprite::behavior::base::{restore_state, save_state};
 use crate::sprite::behavior::collision::base::{CollisionHandler, DelayedMutation};
-use crate::sprite::{CollisionState, Sprite, SpriteMutation};
+use crate::sprite::{CollisionState, DrawingState, Sprite, SpriteMutation};
 use crate::timers::Timer;
-use crate::web_utils::window_time;
 
-#[derive(Copy, Clone, PartialEq)]
+/// The last board column plants are placed in.
+const LAST_BOARD_COL: usize = 9;
+
+/// Zombies keep their armored cells ahead of their special movement, e.g a vaulting zombie holding it's pole.
+#[derive(Copy, Clone, PartialEq, Serialize, Deserialize)]
 enum ZombieState {
+    #[allow(dead_code)]
     Stale = 0,
     ArmoredWalk,
     ArmoredAttack,
     Walk,
     Attack,
     Die,
+    Leap,
 }
 
 impl ZombieState {
@@ -20,79 +34,219 @@ impl ZombieState {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct ZombieCollisionHandler {
+    /// Paces the chomping sound while eating, the eating damage itself is dealt bite by bite by the BattleManager.
     attack_timer: Timer,
+    slow_timer: Timer,
     zombie_state: ZombieState,
     lost_head: bool,
+    armor_kind: Option<ArmorKind>,
+    /// The swap cells index of the zombie walking without it's armor.
+    armor_swap: usize,
+    lost_armor: bool,
+    movement: Option<MovementKind>,
+    moved: bool,
+    /// Set while walking away from the house, as digging zombies do once surfaced.
+    walking_back: bool,
 }
 
 impl ZombieCollisionHandler {
-    pub fn new() -> Self {
+    pub fn new(sprite: &Sprite) -> Self {
+        let armor = &sprite.attack_state.armor;
+
         ZombieCollisionHandler {
             attack_timer: Timer::new(2000.0),
-            zombie_state: ZombieState::ArmoredWalk,
+            slow_timer: Timer::new(SLOW_DURATION),
+            zombie_state: match armor.is_some() || sprite.movement.is_some() {
+                true => ZombieState::ArmoredWalk,
+                false => ZombieState::Walk,
+            },
             lost_head: false,
+            armor_kind: armor.as_ref().map(|armor| armor.kind),
+            armor_swap: armor
+                .as_ref()
+                .map_or(ArmorData::default().swap, |armor| armor.swap),
+            lost_armor: false,
+            movement: sprite.movement,
+            moved: false,
+            walking_back: false,
         }
     }
 
-    fn get_zombie_state(&mut self, state: &CollisionState, life: f64) -> ZombieState {
+    fn get_zombie_state(&mut self, state: &CollisionState, sprite: &Sprite) -> ZombieState {
+        let armored = sprite.attack_state.is_armored() || self.is_movement_ahead();
+
         match state {
-            CollisionState::None | CollisionState::ApplyEffect(_) => match life <= 100.0 {
-                true => ZombieState::Walk,
-                false => ZombieState::ArmoredWalk,
+            CollisionState::None | CollisionState::ApplyEffect(_) => match armored {
+                true => ZombieState::ArmoredWalk,
+                false => ZombieState::Walk,
             },
-            CollisionState::Attacking => match life <= 100.0 {
-                true => ZombieState::Attack,
-                false => ZombieState::ArmoredAttack,
+            CollisionState::Attacking => match armored {
+                true => ZombieState::ArmoredAttack,
+                false => ZombieState::Attack,
             },
-            CollisionState::TakingDamage(_) => match life <= 0.0 {
+            CollisionState::TakingDamage(..) => match sprite.attack_state.is_dead() {
                 true => ZombieState::Die,
                 false => self.zombie_state,
             },
+            CollisionState::Passing => ZombieState::Leap,
         }
     }
 
     fn get_swap_index(&mut self) -> i32 {
-        (self.zombie_state.index() - 1) as i32
+        match self.zombie_state {
+            ZombieState::Walk => self.armor_swap as i32,
+            ZombieState::Attack => self.armor_swap as i32 + 1,
+            zombie_state => (zombie_state.index() - 1) as i32,
+        }
+    }
+
+    fn is_armored(&self) -> bool {
+        matches!(
+            self.zombie_state,
+            ZombieState::ArmoredWalk | ZombieState::ArmoredAttack
+        )
+    }
+
+    fn is_movement_ahead(&self) -> bool {
+        self.movement.is_some() && !self.moved
+    }
+
+    /// The leap taken upon reaching a plant, for zombies moving on contact.
+    fn contact_leap(&self, sprite: &Sprite) -> Option<Position> {
+        match self.movement? {
+            MovementKind::Vault => Some(Position::new(0.0, -VAULT_DISTANCE)),
+            MovementKind::SwitchRow => Some(Position::new(
+                Self::row_switch_offset(sprite.board_location.row),
+                0.0,
+            )),
+            MovementKind::Dig => None,
+        }
+    }
+
+    /// Zombies switch into the row beneath, unless they walk the bottom row.
+    fn row_switch_offset(row: usize) -> f64 {
+        let last_row = ROW_Y_COORD.len() - 1;
+        let target_row = match row < last_row {
+            true => row + 1,
+            false => row - 1,
+        };
+
+        ROW_Y_COORD[target_row] - ROW_Y_COORD[row]
+    }
+
+    /// Tunnels toward the back column, from where swapping into the tunneling cells aligns the zombie.
+    fn dig_offset(sprite: &Sprite) -> Position {
+        let drawing_state = &sprite.drawing_state;
+        let tunnel_cell = &drawing_state.swap_cells[ZombieState::Leap.index() - 1][0];
+        let tunnel_position = LocationBuilder::align_sprite_after_cells_swap(
+            sprite,
+            tunnel_cell,
+            DrawingState::get_active_cell(sprite),
+        );
+
+        Position::new(0.0, COL_X_COORD[1] - tunnel_position.left)
+    }
+
+    fn take_movement(&mut self, offset: Position) -> SpriteMutation {
+        self.moved = true;
+        self.zombie_state = ZombieState::Leap;
+
+        SpriteMutation::new()
+            .leap(offset, self.armor_swap as i32)
+            .swap(self.get_swap_index())
     }
 }
 
 impl CollisionHandler for ZombieCollisionHandler {
-    fn tick(&mut self) -> Option<SpriteMutation> {
-        if self.attack_timer.expired(window_time()) {
-            self.attack_timer.stop(None);
-            return Some(SpriteMutation::new().mute(false));
+    fn tick(&mut self, sprite: &Sprite, now: f64) -> Option<SpriteMutation> {
+        // Digging zombies tunnel right away, surfacing at the back column of their row.
+        if self.movement == Some(MovementKind::Dig) && !self.moved {
+            return Some(self.take_movement(Self::dig_offset(sprite)));
+        }
+
+        // Zombies walking back turn around again once they are through the board.
+        if self.walking_back && sprite.position.left >= COL_X_COORD[LAST_BOARD_COL] {
+            self.walking_back = false;
+            return Some(SpriteMutation::new().turn_around());
+        }
+
+        if self.slow_timer.expired(now) {
+            self.slow_timer.stop(now);
+            return Some(SpriteMutation::new().slow(false));
         }
 
         None
     }
 
-    fn on_attack(&mut self) -> SpriteMutation {
+    fn on_attack(&mut self, now: f64) -> SpriteMutation {
         if self.attack_timer.running {
-            return SpriteMutation::new();
+            if !self.attack_timer.expired(now) {
+                return SpriteMutation::new();
+            }
+
+            self.attack_timer.start(now);
+
+            return SpriteMutation::new().sound(SoundEffect::Chomp);
         }
 
-        self.attack_timer.start();
+        self.attack_timer.start(now);
 
-        SpriteMutation::new().mute(true).swap(self.get_swap_index())
+        // Zombies stay in place while eating, yet keep their attack enabled for the upcoming bites.
+        SpriteMutation::new()
+            .toggle_walking(false)
+            .swap(self.get_swap_index())
+            .sound(SoundEffect::Chomp)
     }
 
-    fn on_hit(&mut self, damage: f64) -> SpriteMutation {
-        SpriteMutation::new()
+    fn on_hit(
+        &mut self,
+        damage: f64,
+        effect: Option<AttackEffect>,
+        trajectory: Trajectory,
+        now: f64,
+    ) -> SpriteMutation {
+        let mutation = SpriteMutation::new()
             .take_damage(damage)
+            .trajectory(trajectory)
             .alpha(0.5)
-            .swap(self.get_swap_index())
+            .swap(self.get_swap_index());
+
+        // Shields take the effects of the attacks they block, along their damage.
+        let shielded = self.is_armored()
+            && self
+                .armor_kind
+                .is_some_and(|kind| kind == ArmorKind::Shield && kind.blocks(trajectory));
+
+        if shielded {
+            return mutation;
+        }
+
+        match effect {
+            // Each chilling hit refreshes the slow duration.
+            Some(AttackEffect::Slow) => {
+                self.slow_timer.start(now);
+                mutation.slow(true)
+            }
+            Some(AttackEffect::Thaw) if self.slow_timer.running => {
+                self.slow_timer.stop(now);
+                mutation.slow(false)
+            }
+            _ => mutation,
+        }
     }
 
     fn on_after_hit(&mut self) -> DelayedMutation {
         (Some(SpriteMutation::new().alpha(1.0)), 50.0)
     }
 
-    fn on_die(&mut self, damage: f64) -> SpriteMutation {
+    fn on_die(&mut self, damage: f64, trajectory: Trajectory) -> SpriteMutation {
         self.zombie_state = ZombieState::Die;
 
         SpriteMutation::new()
             .take_damage(damage)
+            .trajectory(trajectory)
             .alpha(0.9) // TODO - Can be replaced with fadeout effect
             .mute(true)
             .swap(self.get_swap_index())
@@ -104,17 +258,46 @@ impl CollisionHandler for ZombieCollisionHandler {
         sprite: &Sprite,
         state: &CollisionState,
         prev_state: &CollisionState,
+        now: f64,
     ) -> Option<SpriteMutation> {
-        let life = sprite.attack_state.life;
+        // Digging zombies surface behind the plants, walking back along their row.
+        if *prev_state == CollisionState::Passing
+            && self.movement == Some(MovementKind::Dig)
+            && !self.walking_back
+        {
+            self.walking_back = true;
+            self.zombie_state = self.get_zombie_state(state, sprite);
+
+            return Some(
+                SpriteMutation::new()
+                    .turn_around()
+                    .swap(self.get_swap_index()),
+            );
+        }
+
+        // Zombies moving on contact leap upon the first plant they reach, rather than eating it.
+        if *state == CollisionState::Attacking && self.is_movement_ahead() {
+            if let Some(offset) = self.contact_leap(sprite) {
+                return Some(self.take_movement(offset));
+            }
+        }
+
+        let was_armored = self.is_armored();
         let prev_zombie_state = self.zombie_state;
-        self.zombie_state = self.get_zombie_state(state, life);
+        self.zombie_state = self.get_zombie_state(state, sprite);
 
-        // Once Zombie Stop attacking.
-        if state == &CollisionState::None
-            && prev_state == &CollisionState::Attacking
+        // The armor falls off once broken, unless the zombie is dead by then.
+        let armor_broken = sprite.attack_state.armor.is_some() && !sprite.attack_state.is_armored();
+
+        if was_armored && armor_broken && self.zombie_state != ZombieState::Die {
+            self.lost_armor = true;
+        }
+
+        // Once Zombie Stop attacking, even if it's last attacking frame was taken by a hit.
+        if matches!(state, CollisionState::None | CollisionState::ApplyEffect(_))
             && self.attack_timer.running
         {
-            self.attack_timer.stop(None);
+            self.attack_timer.stop(now);
             return Some(
                 SpriteMutation::new()
                     .mute(false)
@@ -136,6 +319,23 @@ impl CollisionHandler for ZombieCollisionHandler {
             return Some(Callback::OnZombieDeath);
         }
 
+        if self.lost_armor {
+            self.lost_armor = false;
+            return Some(Callback::ArmorLost);
+        }
+
         None
     }
+
+    fn is_done(&self) -> bool {
+        self.zombie_state == ZombieState::Die
+    }
+
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value) {
+        restore_state(self, state);
+    }
 }
@@ -1,13 +1,13 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::model::{BehaviorType, Callback, GameInteraction, Position};
-use crate::painter::Painter;
-use crate::sprite::{Sprite, SpriteMutation};
+use crate::sprite::{Outline, Sprite, SpriteMutation};
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Drag {
     anchor: Option<Position>,
     callback: Callback,
@@ -47,6 +47,14 @@ impl Behavior for Drag {
         BehaviorType::Drag
     }
 
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
     fn on_stop(&mut self, _now: f64) {
         self.dragging = false;
         self.anchor = None;
@@ -70,12 +78,11 @@ impl Behavior for Drag {
         _now: f64,
         _last_frame: f64,
         mouse: &Position,
-        context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         let is_dragging = self.dragging;
 
         // Hovers initialise the drag action, and later we flag using the is_dragging for better control (Mouse up triggers the on_stop)
-        let hovering = !is_dragging && Painter::in_path(&sprite.outlines, mouse, context);
+        let hovering = !is_dragging && Outline::in_path(&sprite.outlines, mouse);
 
         if is_dragging || hovering {
             // Mouse is Top / Left, Decrease the delta of the location on the sprite.
@@ -0,0 +1,85 @@
+use derives::{derive_behavior_fields, BaseBehavior};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::base::{restore_state, save_state, Behavior};
+use crate::model::{BehaviorType, Callback, GameInteraction, Position};
+use crate::sprite::{Sprite, SpriteMutation};
+
+/// Fades a Sprite out once it's delay passes, hiding it at the end of the fade.
+#[derive_behavior_fields("")]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
+pub struct Fade {
+    callback: Option<Callback>,
+    delay: f64,
+    duration: f64,
+    start_time: f64,
+}
+
+impl Fade {
+    pub fn new(delay: f64, duration: f64, callback: Option<Callback>) -> Fade {
+        Fade {
+            callback,
+            delay,
+            duration,
+            ..Default::default()
+        }
+    }
+
+    /// Delays the fade so the Sprite is gone once the given lifetime (ms) is over.
+    pub fn set_lifetime(&mut self, lifetime: f64) {
+        self.delay = (lifetime - self.duration).max(0.0);
+    }
+}
+
+impl Behavior for Fade {
+    fn name(&self) -> BehaviorType {
+        BehaviorType::Fade
+    }
+
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
+    fn get_interaction(&self) -> Option<GameInteraction> {
+        if !self.interaction_active || self.callback.is_none() {
+            return None;
+        }
+
+        Some(GameInteraction::AnimationCallback(
+            self.callback.unwrap(),
+            self.sprite_id.clone(),
+        ))
+    }
+
+    fn on_start(&mut self, now: f64) {
+        self.start_time = now;
+    }
+
+    fn execute(
+        &mut self,
+        _sprite: &Sprite,
+        now: f64,
+        _last_frame: f64,
+        _mouse: &Position,
+    ) -> Option<SpriteMutation> {
+        let fading_time = now - self.start_time - self.delay;
+
+        if fading_time < 0.0 {
+            return None;
+        }
+
+        if fading_time >= self.duration {
+            self.stop(now);
+            self.interaction_active = true;
+
+            return Some(SpriteMutation::new().hide(true));
+        }
+
+        Some(SpriteMutation::new().alpha(1.0 - fading_time / self.duration))
+    }
+}
@@ -1,13 +1,13 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::model::{BehaviorType, Position};
-use crate::painter::Painter;
-use crate::sprite::{Sprite, SpriteMutation};
+use crate::sprite::{Outline, Sprite, SpriteMutation};
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Hover {}
 
 impl Hover {
@@ -23,17 +23,24 @@ impl Behavior for Hover {
         BehaviorType::Hover
     }
 
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
     fn execute(
         &mut self,
         sprite: &Sprite,
         now: f64,
         _last_frame: f64,
         mouse: &Position,
-        context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         self.stop(now);
 
-        let hovered = Painter::in_path(&sprite.outlines, mouse, context);
+        let hovered = Outline::in_path(&sprite.outlines, mouse);
 
         Some(SpriteMutation::new().hovered(hovered))
     }
@@ -1,16 +1,16 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::model::{BehaviorType, Callback, GameInteraction, Position};
 use crate::sprite::{Sprite, SpriteMutation};
 use crate::timers::Timer;
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Interval {
     pub callback: Option<Callback>,
-    pub interval: f64,
     timer: Timer,
 }
 
@@ -18,7 +18,6 @@ impl Interval {
     pub fn new(interval: f64, callback: Option<Callback>) -> Interval {
         Interval {
             callback,
-            interval,
             timer: Timer::new(interval),
             ..Default::default()
         }
@@ -30,12 +29,20 @@ impl Behavior for Interval {
         BehaviorType::Interval
     }
 
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
     fn on_stop(&mut self, now: f64) {
-        self.timer.reset(Some(now))
+        self.timer.reset(now)
     }
 
-    fn on_start(&mut self, _now: f64) {
-        self.timer.start();
+    fn on_start(&mut self, now: f64) {
+        self.timer.start(now);
     }
 
     fn get_interaction(&self) -> Option<GameInteraction> {
@@ -55,7 +62,6 @@ impl Behavior for Interval {
         now: f64,
         _last_frame: f64,
         _mouse: &Position,
-        _context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         if self.timer.expired(now) {
             match self.callback {
@@ -63,7 +69,7 @@ impl Behavior for Interval {
                 Some(_) => self.interaction_active = true,
             }
 
-            self.timer.reset(Some(now));
+            self.timer.reset(now);
         }
 
         None
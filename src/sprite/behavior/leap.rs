@@ -0,0 +1,79 @@
+use derives::{derive_behavior_fields, BaseBehavior};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::base::{restore_state, save_state, Behavior};
+use crate::model::{BehaviorType, Position};
+use crate::sprite::{Sprite, SpriteMutation};
+
+/// Moves a Sprite by a given offset within it's duration, passing over (or under) the other Sprites meanwhile.
+/// Once landed, the Sprite walks and attacks as usual.
+#[derive_behavior_fields("")]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
+pub struct Leap {
+    duration: f64,
+    offset: Position,
+    leaped_time: f64,
+    /// Swapped into once landed, as the cells leaped by may collide the Sprites passed by.
+    landing_swap: i32,
+}
+
+impl Leap {
+    pub fn new(duration: f64) -> Leap {
+        Leap {
+            duration,
+            ..Default::default()
+        }
+    }
+
+    /// Leaps the given offset on, from where the Sprite currently is.
+    pub fn set_offset(&mut self, offset: Position, landing_swap: i32) {
+        self.offset = offset;
+        self.landing_swap = landing_swap;
+        self.leaped_time = 0.0;
+    }
+}
+
+impl Behavior for Leap {
+    fn name(&self) -> BehaviorType {
+        BehaviorType::Leap
+    }
+
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
+    fn execute(
+        &mut self,
+        sprite: &Sprite,
+        now: f64,
+        last_frame: f64,
+        _mouse: &Position,
+    ) -> Option<SpriteMutation> {
+        let frame_time = (now - last_frame).min(self.duration - self.leaped_time);
+        let part = match self.duration > 0.0 {
+            true => frame_time / self.duration,
+            false => 1.0,
+        };
+
+        self.leaped_time += frame_time;
+
+        let new_position = Position::new(
+            sprite.position.top + self.offset.top * part,
+            sprite.position.left + self.offset.left * part,
+        );
+        let mutation = SpriteMutation::new().position(new_position);
+
+        if self.leaped_time < self.duration {
+            return Some(mutation);
+        }
+
+        self.stop(now);
+
+        Some(mutation.swap(self.landing_swap).passing(false).mute(false))
+    }
+}
@@ -2,15 +2,16 @@ pub use animate::Animate;
 pub use base::Behavior;
 pub use click::Click;
 pub use collision::{Collision, CollisionState};
+pub use fade::Fade;
 pub use hover::Hover;
 pub use interval::Interval;
+pub use leap::Leap;
 pub use scroll::Scroll;
 pub use walk::Walk;
-use web_sys::CanvasRenderingContext2d;
 
 use crate::model::{BehaviorData, BehaviorType, GameInteraction, Position};
 use crate::sprite::behavior::drag::Drag;
-use crate::sprite::{Sprite, SpriteMutation};
+use crate::sprite::{Outline, Sprite, SpriteMutation};
 use crate::timers::GameTime;
 
 mod animate;
@@ -18,8 +19,10 @@ mod base;
 mod click;
 mod collision;
 mod drag;
+mod fade;
 mod hover;
 mod interval;
+mod leap;
 mod scroll;
 mod walk;
 
@@ -30,7 +33,7 @@ impl BehaviorManager {
         let behavior_type = BehaviorType::from_string(&data.name);
 
         let mut behavior: Box<dyn Behavior> = match behavior_type {
-            BehaviorType::Click => Box::new(Click::new(data.callback.unwrap())),
+            BehaviorType::Click => Box::new(Click::new(data.callback.unwrap(), data.while_paused)),
             BehaviorType::Animate => Box::new(Animate::new(
                 data.rate,
                 data.callback,
@@ -49,6 +52,12 @@ impl BehaviorManager {
             BehaviorType::Collision => {
                 Box::new(Collision::new(data.collision_margin.unwrap_or_default()))
             }
+            BehaviorType::Fade => Box::new(Fade::new(
+                data.callback_delay.unwrap_or_default(),
+                data.duration.unwrap(),
+                data.callback,
+            )),
+            BehaviorType::Leap => Box::new(Leap::new(data.duration.unwrap_or_default())),
         };
 
         behavior.set_sprite_id(sprite_id);
@@ -56,24 +65,30 @@ impl BehaviorManager {
         behavior
     }
 
-    pub fn run(
-        sprite: &Sprite,
-        time: &GameTime,
-        position: &Position,
-        context: &CanvasRenderingContext2d,
-    ) -> Vec<SpriteMutation> {
+    pub fn run(sprite: &Sprite, time: &GameTime, position: &Position) -> Vec<SpriteMutation> {
         sprite
             .mutable_behaviors()
             .iter_mut()
             .filter(|behavior| behavior.is_running())
-            .filter_map(|behavior| {
-                behavior.execute(sprite, time.time, time.last_timestamp, position, context)
-            })
+            .filter(|behavior| !time.paused || behavior.runs_while_paused())
+            .filter_map(|behavior| behavior.execute(sprite, time.time, time.last_timestamp, position))
             .collect()
     }
 
+    /// Whether the mouse is over a sprite clickable while the game is paused.
+    pub fn hovers_paused_click(sprites: &[Sprite], mouse: &Position) -> bool {
+        sprites
+            .iter()
+            .filter(|sprite| sprite.visible && Outline::in_path(&sprite.outlines, mouse))
+            .any(|sprite| {
+                sprite.mutable_behaviors().iter().any(|behavior| {
+                    behavior.name() == BehaviorType::Click && behavior.runs_while_paused()
+                })
+            })
+    }
+
     pub fn toggle_behaviors(
-        sprites: &Vec<Sprite>,
+        sprites: &[Sprite],
         behavior_types: &[BehaviorType],
         should_run: bool,
         now: f64,
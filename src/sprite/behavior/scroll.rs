@@ -1,12 +1,13 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::model::{BehaviorType, Callback, GameInteraction, Position};
 use crate::sprite::{Sprite, SpriteMutation};
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Scroll {
     callback: Callback,
     distance: f64,
@@ -42,6 +43,14 @@ impl Behavior for Scroll {
         BehaviorType::Scroll
     }
 
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
     fn get_interaction(&self) -> Option<GameInteraction> {
         if self.interaction_active {
             return Some(GameInteraction::SpriteClick(
@@ -59,7 +68,6 @@ impl Behavior for Scroll {
         now: f64,
         last_frame: f64,
         _mouse: &Position,
-        _context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         let finished = self.scrolled_distance.abs() >= self.distance;
 
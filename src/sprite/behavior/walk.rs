@@ -1,17 +1,20 @@
 use derives::{derive_behavior_fields, BaseBehavior};
-use web_sys::CanvasRenderingContext2d;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
 
-use super::base::Behavior;
+use super::base::{restore_state, save_state, Behavior};
 use crate::board::Board;
+use crate::constants::SLOW_FACTOR;
 use crate::model::{BehaviorType, Position, Velocity};
 use crate::sprite::{Sprite, SpriteMutation};
 
 #[derive_behavior_fields("")]
-#[derive(BaseBehavior, Default)]
+#[derive(BaseBehavior, Default, Serialize, Deserialize)]
 pub struct Walk {
     pub velocity: Velocity,
     max_distance: f64,
     walked_distance: f64,
+    slowed: bool,
 }
 
 impl Walk {
@@ -23,10 +26,26 @@ impl Walk {
         }
     }
 
+    /// Walks the given distance on, from where the Sprite currently is.
+    pub fn set_distance(&mut self, distance: f64) {
+        self.max_distance = distance;
+        self.walked_distance = 0.0;
+    }
+
+    /// Walks the opposite way, keeping the same speed.
+    pub fn turn_around(&mut self) {
+        self.velocity.x = -self.velocity.x;
+    }
+
     fn calculate_offset(&mut self, animation_rate: f64) -> Position {
+        let speed = match self.slowed {
+            true => SLOW_FACTOR,
+            false => 1.0,
+        };
+
         Position::new(
-            animation_rate * self.velocity.y,
-            animation_rate * self.velocity.x,
+            animation_rate * self.velocity.y * speed,
+            animation_rate * self.velocity.x * speed,
         )
     }
 
@@ -40,13 +59,24 @@ impl Behavior for Walk {
         BehaviorType::Walk
     }
 
+    fn save_state(&self) -> Value {
+        save_state(self)
+    }
+
+    fn restore_state(&mut self, state: Value, _sprite: &Sprite) {
+        restore_state(self, state);
+    }
+
+    fn slow_down(&mut self, slowed: bool) {
+        self.slowed = slowed;
+    }
+
     fn execute(
         &mut self,
         sprite: &Sprite,
         now: f64,
         last_frame: f64,
         _mouse: &Position,
-        _context: &CanvasRenderingContext2d,
     ) -> Option<SpriteMutation> {
         let finished = self.max_distance > 0.0 && self.walked_distance.abs() >= self.max_distance;
 
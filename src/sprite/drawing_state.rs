@@ -11,6 +11,9 @@ pub struct DrawingState {
     pub offset: Position,
     pub alpha: f64,
     pub grayscale: bool,
+    pub chilled: bool,
+    /// The part (0 - 1) of a recharging card left to recharge.
+    pub recharge: f64,
 }
 
 impl DrawingState {
@@ -7,9 +7,7 @@ mod outline;
 mod text_overlay;
 
 pub use base::Sprite;
-pub use behavior::{
-    Animate, BehaviorManager, Click, Collision, CollisionState, Hover, Interval, Scroll, Walk,
-};
+pub use behavior::{BehaviorManager, Click, Collision, CollisionState, Fade, Scroll, Walk};
 pub use drawing_state::DrawingState;
 pub use mutations::SpriteMutation;
 pub use outline::Outline;
@@ -1,6 +1,8 @@
-use crate::model::Position;
+use serde_derive::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+use crate::model::{AttackEffect, Position, SoundEffect, Trajectory};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpriteMutation {
     pub position: Option<Position>,
     pub offset: Option<Position>,
@@ -11,9 +13,18 @@ pub struct SpriteMutation {
     pub mute: Option<bool>,
     pub damage: Option<f64>,
     pub taken_damage: Option<f64>,
+    /// The path of the attack the damage is taken from.
+    pub trajectory: Option<Trajectory>,
     pub alpha: Option<f64>,
     pub walking: Option<bool>,
     pub stop_animate: Option<bool>,
+    pub slowed: Option<bool>,
+    pub attack_effect: Option<AttackEffect>,
+    pub sound: Option<SoundEffect>,
+    /// The offset to leap by, along the swap cells to land by, see the Leap behavior.
+    pub leap: Option<(Position, i32)>,
+    pub passing: Option<bool>,
+    pub turn_around: Option<bool>,
 }
 
 impl SpriteMutation {
@@ -26,11 +37,18 @@ impl SpriteMutation {
             visible: None,
             damage: None,
             taken_damage: None,
+            trajectory: None,
             swap: None,
             mute: None,
             alpha: None,
             walking: None,
             stop_animate: None,
+            slowed: None,
+            attack_effect: None,
+            sound: None,
+            leap: None,
+            passing: None,
+            turn_around: None,
         }
     }
 
@@ -70,6 +88,12 @@ impl SpriteMutation {
         self
     }
 
+    pub fn trajectory(mut self, trajectory: Trajectory) -> Self {
+        self.trajectory = Some(trajectory);
+
+        self
+    }
+
     pub fn increase_damage(mut self, damage: f64) -> Self {
         self.damage = Some(damage);
 
@@ -105,4 +129,40 @@ impl SpriteMutation {
 
         self
     }
+
+    pub fn slow(mut self, slowed: bool) -> Self {
+        self.slowed = Some(slowed);
+
+        self
+    }
+
+    pub fn attack_effect(mut self, effect: AttackEffect) -> Self {
+        self.attack_effect = Some(effect);
+
+        self
+    }
+
+    pub fn sound(mut self, sound: SoundEffect) -> Self {
+        self.sound = Some(sound);
+
+        self
+    }
+
+    pub fn leap(mut self, offset: Position, landing_swap: i32) -> Self {
+        self.leap = Some((offset, landing_swap));
+
+        self
+    }
+
+    pub fn passing(mut self, passing: bool) -> Self {
+        self.passing = Some(passing);
+
+        self
+    }
+
+    pub fn turn_around(mut self) -> Self {
+        self.turn_around = Some(true);
+
+        self
+    }
 }
@@ -42,14 +42,12 @@ impl MarchingSquares {
         height: i32,
     ) -> Option<(i32, i32)> {
         for h in 0..height {
-            let mut idx = h * width;
+            let row_start = h * width;
 
             for w in 0..width {
-                if data[idx as usize] > 0 {
+                if data[(row_start + w) as usize] > 0 {
                     return Some((w, h));
                 }
-
-                idx += 1;
             }
         }
 
@@ -20,13 +20,42 @@ impl Outline {
 
         let size = cell.into();
 
-        if exact {
+        // Exact outlines are traced from the Sprite image, which is not loaded when running headless.
+        if exact && sprite.image.is_some() {
             return Outline::get_exact_outlines(&sprite.image, cell, position, size, scale);
         }
 
         Outline::get_rect_outlines(position, size, scale)
     }
 
+    /// Checks rather a given point is within the outline shape (even-odd ray casting).
+    pub fn in_path(outline: &[Position], point: &Position) -> bool {
+        if outline.is_empty() {
+            return false;
+        }
+
+        let mut inside = false;
+        let mut previous = &outline[outline.len() - 1];
+
+        for current in outline {
+            let crosses = (current.top > point.top) != (previous.top > point.top);
+
+            if crosses {
+                let cross_left = (previous.left - current.left) * (point.top - current.top)
+                    / (previous.top - current.top)
+                    + current.left;
+
+                if point.left < cross_left {
+                    inside = !inside;
+                }
+            }
+
+            previous = current;
+        }
+
+        inside
+    }
+
     pub fn get_rect_outlines(offset: &Position, size: Size, scale: f64) -> Vec<Position> {
         let scale_left = size.width * scale;
         let scale_top = size.height * scale;
@@ -65,6 +94,7 @@ impl Outline {
             scale,
             1.0,
             false,
+            false,
         );
 
         let image_data = painter
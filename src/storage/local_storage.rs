@@ -0,0 +1,31 @@
+use crate::engine::EngineError;
+use crate::storage::Storage;
+use crate::web_utils::window;
+
+/// Persists into the browser `localStorage`, surviving page reloads.
+pub struct LocalStorage;
+
+impl LocalStorage {
+    fn storage() -> Option<web_sys::Storage> {
+        window().local_storage().ok().flatten()
+    }
+}
+
+impl Storage for LocalStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        Self::storage()?.get_item(key).ok().flatten()
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), EngineError> {
+        match Self::storage() {
+            Some(storage) => Ok(storage.set_item(key, value)?),
+            None => Err(EngineError::Js("`localStorage` is not available".into())),
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(storage) = Self::storage() {
+            storage.remove_item(key).ok();
+        }
+    }
+}
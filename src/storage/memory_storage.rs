@@ -0,0 +1,37 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::engine::EngineError;
+use crate::storage::Storage;
+
+/// Keeps stored values in memory, e.g for headless games.
+/// Clones share the same values, so a stored progress can be handed over to a new game.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    values: Rc<RefCell<HashMap<String, String>>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.borrow().get(key).cloned()
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), EngineError> {
+        self.values
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string());
+
+        Ok(())
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.values.borrow_mut().remove(key);
+    }
+}
@@ -0,0 +1,18 @@
+mod local_storage;
+mod memory_storage;
+mod save_data;
+
+pub use local_storage::LocalStorage;
+pub use memory_storage::MemoryStorage;
+pub use save_data::{SaveData, Settings};
+
+use crate::engine::EngineError;
+
+/// A key / value store, persisting the game progress between sessions.
+pub trait Storage {
+    fn get(&self, key: &str) -> Option<String>;
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), EngineError>;
+
+    fn remove(&mut self, key: &str);
+}
@@ -0,0 +1,125 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::engine::EngineError;
+use crate::log;
+use crate::model::LevelData;
+use crate::storage::Storage;
+
+/// The current save format version, to be bumped upon breaking changes of the save model.
+pub const SAVE_VERSION: u32 = 1;
+
+const SAVE_KEY: &str = "plants-vs-zombies/save";
+
+/// The player persisted progress and settings.
+/// Missing fields fall back to their defaults, so saves of older versions are loaded as is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveData {
+    pub version: u32,
+    /// Ids of the levels won so far.
+    pub completed_levels: Vec<String>,
+    /// Plants the player got to use along the campaign.
+    pub unlocked_plants: Vec<String>,
+    /// Most waves survived within a single survival run.
+    pub survival_best: usize,
+    pub settings: Settings,
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        SaveData {
+            version: SAVE_VERSION,
+            completed_levels: vec![],
+            unlocked_plants: vec![],
+            survival_best: 0,
+            settings: Settings::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The game speed multiplier.
+    pub speed: f64,
+    /// The audio volume (0 - 1).
+    pub volume: f64,
+    pub muted: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            speed: 1.0,
+            volume: 1.0,
+            muted: false,
+        }
+    }
+}
+
+impl SaveData {
+    /// Loads the stored save, starting over when missing or unreadable.
+    pub fn load(storage: &dyn Storage) -> SaveData {
+        match storage.get(SAVE_KEY).map(|json| Self::from_json(&json)) {
+            Some(Ok(save_data)) => save_data,
+            Some(Err(error)) => {
+                log!("Cannot load saved progress, starting over: {:?}", error);
+                SaveData::default()
+            }
+            None => SaveData::default(),
+        }
+    }
+
+    /// Stores the save, unless a newer game version stored it's own which would be lost.
+    pub fn store(&self, storage: &mut dyn Storage) -> Result<(), EngineError> {
+        if let Some(stored_version) = Self::stored_version(storage) {
+            Self::check_version(stored_version)?;
+        }
+
+        storage.set(SAVE_KEY, &self.to_json())
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_json(json: &str) -> Result<SaveData, EngineError> {
+        let mut save_data: SaveData = serde_json::from_str(json)?;
+
+        Self::check_version(save_data.version)?;
+
+        // Older saves are upgraded by their defaulted fields.
+        save_data.version = SAVE_VERSION;
+
+        Ok(save_data)
+    }
+
+    /// Marks the level as completed, unlocking the plants used within it.
+    pub fn complete_level(&mut self, level: &LevelData) {
+        if !self.completed_levels.contains(&level.name) {
+            self.completed_levels.push(level.name.clone());
+        }
+
+        level.plant_cards.iter().for_each(|plant| {
+            if !self.unlocked_plants.contains(plant) {
+                self.unlocked_plants.push(plant.clone());
+            }
+        });
+    }
+
+    /// Saves of newer versions are rejected, as their progress cannot be read without loss.
+    fn check_version(version: u32) -> Result<(), EngineError> {
+        match version > SAVE_VERSION {
+            true => Err(EngineError::SaveVersion(version)),
+            false => Ok(()),
+        }
+    }
+
+    /// The version of the stored save, None when there is none or it's unreadable.
+    pub fn stored_version(storage: &dyn Storage) -> Option<u32> {
+        let json = storage.get(SAVE_KEY)?;
+        let save: serde_json::Value = serde_json::from_str(&json).ok()?;
+
+        save.get("version")?.as_u64().map(|version| version as u32)
+    }
+}
@@ -1,11 +1,19 @@
+use serde_derive::{Deserialize, Serialize};
+
 use crate::features::GameFeatures;
 use crate::game::Game;
 use crate::location_builder::LocationBuilder;
-use crate::model::{BehaviorType, Position, TextOverlayData};
+use crate::model::{BehaviorType, Position, SunData, SunSize, TextOverlayData};
 use crate::resource_loader::ResourceKind;
-use crate::sprite::{Animate, BehaviorManager, Sprite, TextOverlay, Walk};
+use crate::sprite::{BehaviorManager, Fade, Sprite, TextOverlay, Walk};
+
+/// All sun sizes are drawn by the same Sprite, scaled to size.
+const SUN: &str = "Sun";
 
-#[derive(Debug, Default)]
+/// Distance (px) Sunflower sun falls, once it pops out of it's Sunflower.
+const SUNFLOWER_SUN_FALL: f64 = 80.0;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SunState {
     pub last_generated: f64,
     pub sun_interval: f64,
@@ -14,10 +22,14 @@ pub struct SunState {
 
 impl SunState {
     pub fn new() -> Self {
+        Self::from_data(&SunData::default())
+    }
+
+    pub fn from_data(sun: &SunData) -> Self {
         SunState {
-            score: 275,
+            score: sun.starting,
             last_generated: 0.0,
-            sun_interval: 15.0 * 1000.0,
+            sun_interval: sun.interval,
         }
     }
 
@@ -29,8 +41,20 @@ impl SunState {
 pub struct SunManager;
 
 impl SunManager {
+    pub fn start_level(game: &mut Game) {
+        game.state.sun_state = SunState::from_data(&game.state.get_level().rules.sun);
+    }
+
+    /// Drops sky sun by the level sun rules, none at night.
+    pub fn start_battle(game: &mut Game) {
+        GameFeatures::enable_generate_sun(!game.state.get_level().rules.sun.night);
+    }
+
     pub fn tick(game: &mut Game) {
         let now = game.game_time.time;
+
+        Self::fade_landed_sun(game);
+
         let state = &game.state.sun_state;
 
         if GameFeatures::should_generate_sun() {
@@ -57,41 +81,33 @@ impl SunManager {
                 .find(|sprite| sprite.name == "SunScore");
 
             if let Some(sun_score) = sun_score {
-                sun_score.text_overlay = Some(TextOverlay::new(
-                    &TextOverlayData {
-                        text: format!("{}", score),
-                        size: 24,
-                        offset: Some(Position::new(6.0, 14.0)),
-                        location_type: Default::default(),
-                        color: Some(String::from("black")),
-                    },
-                    sun_score,
-                ));
+                sun_score.text_overlay = Some(TextOverlay::new(&TextOverlayData {
+                    text: format!("{}", score),
+                    size: 24,
+                    offset: Some(Position::new(6.0, 14.0)),
+                    location_type: Default::default(),
+                    color: Some(String::from("black")),
+                }));
             }
         }
     }
 
     pub fn generate_sunflower_sun(game: &mut Game, source_position: Position) {
+        let sun_data = game.state.get_level().rules.sun;
         let mut sun = Self::create_sun(
             game,
+            &sun_data.sunflower_sun,
             Position::new(source_position.top - 50.0, source_position.left + 30.0),
         );
 
         sun.iter_mut().for_each(|sun_sprite| {
-            let animate = BehaviorManager::get_sprite_behavior(sun_sprite, BehaviorType::Animate)
-                .as_any()
-                .downcast_mut::<Animate>()
-                .unwrap();
-
-            animate.set_max_cycles(12);
-
             let walk = BehaviorManager::get_sprite_behavior(sun_sprite, BehaviorType::Walk)
                 .as_any()
                 .downcast_mut::<Walk>()
                 .unwrap();
 
-            walk.velocity.y = -75.0;
-            walk.velocity.x = 30.0;
+            walk.velocity.y = sun_data.sunflower_velocity.y;
+            walk.velocity.x = sun_data.sunflower_velocity.x;
         });
 
         // Triggers Interval which will reverse the direction at the end of it's first tick
@@ -115,10 +131,24 @@ impl SunManager {
 
         walk.velocity.y = 20.0;
         walk.velocity.x = 0.0;
+        walk.set_distance(SUNFLOWER_SUN_FALL);
     }
 
     pub fn collect_sun(game: &mut Game, sun_sprite_id: &String) {
-        Self::change_score(game, 25);
+        let sun = game.get_sprite_by_id(sun_sprite_id);
+        let sun_size = SunSize::from_scale(sun.drawing_state.scale)
+            .unwrap_or_else(|| panic!("Unknown sun size of {}", sun.id));
+        let value = sun_size.sun_value(&game.state.get_level().rules.sun);
+
+        Self::change_score(game, value);
+        game.state.battle_stats.collected_sun += 1;
+
+        game.remove_sprite_by_id(sun_sprite_id);
+    }
+
+    /// Removes a sun which faded away uncollected.
+    pub fn miss_sun(game: &mut Game, sun_sprite_id: &String) {
+        game.state.battle_stats.missed_sun += 1;
 
         game.remove_sprite_by_id(sun_sprite_id);
     }
@@ -127,19 +157,51 @@ impl SunManager {
         game.state.sun_state.change_score(addition);
     }
 
+    /// Sun starts fading once landed, as it's lifetime is counted from the moment it lands.
+    fn fade_landed_sun(game: &mut Game) {
+        let now = game.game_time.time;
+
+        game.sprites
+            .iter()
+            .filter(|sprite| sprite.visible && sprite.name == SUN)
+            .filter(|sprite| {
+                !sprite.mutable_behaviors().iter().any(|behavior| {
+                    [BehaviorType::Walk, BehaviorType::Fade].contains(&behavior.name())
+                        && behavior.is_running()
+                })
+            })
+            .for_each(|sun| {
+                BehaviorManager::toggle_sprite_behaviors(sun, &[BehaviorType::Fade], true, now)
+            });
+    }
+
     fn generate_random_sun(game: &mut Game) {
-        let mut sun = Self::create_sun(game, LocationBuilder::sun_location());
+        let sun_size = game.state.get_level().rules.sun.sky_sun;
+        let sun_location = LocationBuilder::sun_location(&mut game.random);
+        let mut sun = Self::create_sun(game, &sun_size, sun_location);
 
         game.add_sprites(sun.as_mut());
     }
 
-    fn create_sun(game: &mut Game, position: Position) -> Vec<Sprite> {
-        let mut sun_sprite =
-            Sprite::create_sprites(vec!["Sun"], &ResourceKind::Interface, &game.resources);
+    fn create_sun(game: &mut Game, size: &SunSize, position: Position) -> Vec<Sprite> {
+        let lifetime = game.state.get_level().rules.sun.lifetime;
+        let mut sun_sprite = Sprite::create_sprites(
+            vec![SUN],
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
 
-        sun_sprite
-            .iter_mut()
-            .for_each(|sprite| sprite.update_position(position));
+        sun_sprite.iter_mut().for_each(|sprite| {
+            sprite.update_position(position);
+            sprite.drawing_state.scale = size.scale();
+
+            BehaviorManager::get_sprite_behavior(sprite, BehaviorType::Fade)
+                .as_any()
+                .downcast_mut::<Fade>()
+                .unwrap()
+                .set_lifetime(lifetime);
+        });
 
         BehaviorManager::toggle_behaviors(
             &sun_sprite,
@@ -0,0 +1,209 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::features::GameFeatures;
+use crate::game::Game;
+use crate::model::{BehaviorType, Callback, LevelData, Music, SpriteType, WaveData};
+use crate::random::Random;
+use crate::resource_loader::ResourceKind;
+use crate::scene::{BattleScene, PlantsChooser};
+use crate::sprite::{BehaviorManager, Click, Sprite};
+use crate::sun_manager::SunManager;
+use crate::wave_manager::WaveState;
+
+/// The level data survival rounds are generated from, it's waves make up the first round.
+pub const SURVIVAL_LEVEL: &str = "survival";
+
+/// Zombies joining each survival round waves, from the weakest to the toughest.
+const SURVIVAL_ZOMBIES: [&str; 7] = [
+    "Zombie1",
+    "Conehead",
+    "ScreenDoor",
+    "Buckethead",
+    "Snorkel",
+    "PoleVaulting",
+    "BackupDancer",
+];
+
+/// Life added to the zombies of each round, on top of the previous round.
+const ROUND_EXTRA_LIFE: f64 = 25.0;
+
+/// Waves delay (ms) shortening of each round, never below half the original delay.
+const ROUND_DELAY_DECREASE: f64 = 1000.0;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SurvivalState {
+    /// The current round, each closed by a flag wave.
+    pub round: usize,
+    /// Waves of the rounds cleared so far.
+    pub cleared_waves: usize,
+}
+
+impl SurvivalState {
+    pub fn new() -> Self {
+        SurvivalState {
+            round: 0,
+            cleared_waves: 0,
+        }
+    }
+}
+
+pub struct SurvivalManager;
+
+impl SurvivalManager {
+    /// Starts an endless survival run, played as a level whose waves are renewed every round.
+    pub fn start(game: &mut Game, seed: u64) {
+        let level = Self::round_level(game, 0, seed);
+
+        game.start_level(level, seed);
+        game.state.survival = Some(SurvivalState::new());
+
+        let mut score = Sprite::create_sprite(
+            "SurvivalScore",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+
+        game.add_sprites(score.as_mut());
+    }
+
+    pub fn tick(game: &mut Game) {
+        if game.state.survival.is_none() {
+            return;
+        }
+
+        let text = format!(
+            "Waves: {}  Best: {}",
+            Self::score(game),
+            game.save_data.survival_best
+        );
+        let sign = game
+            .sprites
+            .iter_mut()
+            .find(|sprite| sprite.name == "SurvivalScore");
+
+        if let Some(text_overlay) = sign.and_then(|sign| sign.text_overlay.as_mut()) {
+            if text_overlay.text != text {
+                text_overlay.text = text;
+                // Re-measured upon the next draw
+                text_overlay.position.set(None);
+            }
+        }
+    }
+
+    /// The number of waves survived, where a wave is survived once it's followed by the next one.
+    pub fn score(game: &Game) -> usize {
+        match &game.state.survival {
+            Some(survival) => {
+                survival.cleared_waves + game.state.wave_state.next_wave.saturating_sub(1)
+            }
+            None => 0,
+        }
+    }
+
+    /// Closes the cleared round, letting the player re-select plants ahead of the next one.
+    /// The board is kept as is, plants included.
+    pub fn complete_round(game: &mut Game) {
+        let cleared_waves = game.state.get_level().waves.len();
+        let round = match game.state.survival.as_mut() {
+            Some(survival) => {
+                survival.round += 1;
+                survival.cleared_waves += cleared_waves;
+                survival.round
+            }
+            None => return,
+        };
+
+        game.state.current_level = Some(Self::round_level(game, round, game.state.seed));
+        game.state.wave_state = WaveState::new();
+
+        BattleScene::reset_flag_meter(game);
+
+        GameFeatures::enable_spawn_waves(false);
+        GameFeatures::enable_generate_sun(false);
+
+        Self::show_plants_chooser(game);
+    }
+
+    /// Continues into the next round once plants are chosen.
+    pub fn resume(game: &mut Game) {
+        PlantsChooser::clear(game);
+
+        let mut sun_score = Sprite::create_sprite(
+            "SunScore",
+            &ResourceKind::Interface,
+            &game.resources,
+            &mut game.random,
+        );
+        game.add_sprites(sun_score.as_mut());
+
+        BattleScene::make_plant_cards_draggable(game);
+        BattleScene::toggle_cards_grayscale(game);
+
+        GameFeatures::enable_spawn_waves(true);
+        SunManager::start_battle(game);
+
+        game.audio.play_music(&game.resources, Music::Battle);
+    }
+
+    /// Keeps the best survival score once the run is over.
+    pub fn finish(game: &mut Game) {
+        let score = Self::score(game);
+
+        game.save_data.survival_best = game.save_data.survival_best.max(score);
+    }
+
+    fn show_plants_chooser(game: &mut Game) {
+        BattleScene::cancel_drag(game);
+
+        // Chosen cards are replaced, and the chooser brings it's own sun score.
+        game.remove_sprites_by_type(&SpriteType::Card);
+        game.remove_sprites_by_name(vec!["SunScore"]);
+        game.state.selected_seeds = vec![];
+
+        PlantsChooser::show(game);
+
+        let ok_button = game.get_sprite_by_name_and_type("OkButton", &SpriteType::Interface);
+
+        BehaviorManager::get_sprite_behavior(ok_button, BehaviorType::Click)
+            .as_any()
+            .downcast_mut::<Click>()
+            .unwrap()
+            .callback = Callback::ResumeSurvival;
+
+        game.audio.play_music(&game.resources, Music::Chooser);
+    }
+
+    /// Builds the level of the given round, scaling the survival level waves in count and toughness.
+    /// Each round draws from it's own randomness, so rounds are reproducible by the run seed alone.
+    fn round_level(game: &Game, round: usize, seed: u64) -> LevelData {
+        let level = game.resources.get_level_data(SURVIVAL_LEVEL);
+        let mut random = Random::new(seed.wrapping_add(round as u64));
+
+        // Tougher zombies join as rounds go by.
+        let zombies_pool = &SURVIVAL_ZOMBIES[..(round + 1).min(SURVIVAL_ZOMBIES.len())];
+
+        let waves = level
+            .waves
+            .iter()
+            .map(|wave| {
+                let mut zombies = wave.zombies.clone();
+
+                (0..round).for_each(|_| {
+                    let index = random.range(0.0, (zombies_pool.len() - 1) as f64) as usize;
+
+                    zombies.push(String::from(zombies_pool[index]));
+                });
+
+                WaveData {
+                    delay: (wave.delay - ROUND_DELAY_DECREASE * round as f64).max(wave.delay / 2.0),
+                    zombies,
+                    extra_life: wave.extra_life + ROUND_EXTRA_LIFE * round as f64,
+                    ..wave.clone()
+                }
+            })
+            .collect();
+
+        LevelData { waves, ..level }
+    }
+}
@@ -1,6 +1,6 @@
-use crate::web_utils::window_time;
+use serde_derive::{Deserialize, Serialize};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Timer {
     elapsed: f64,
     pub running: bool,
@@ -20,26 +20,18 @@ impl Timer {
         self.elapsed = elapsed;
     }
 
-    pub fn get_current_time(&self) -> f64 {
-        window_time()
-    }
-
-    pub fn start(&mut self) {
-        // TODO - Add Start time as optional
+    pub fn start(&mut self, now: f64) {
         self.running = true;
-        self.start_time = self.get_current_time();
+        self.start_time = now;
     }
 
-    pub fn stop(&mut self, now: Option<f64>) {
+    pub fn stop(&mut self, now: f64) {
         self.reset(now);
         self.running = false;
     }
 
-    pub fn reset(&mut self, now: Option<f64>) {
-        self.start_time = match now {
-            Some(now) => now,
-            None => self.get_current_time(),
-        };
+    pub fn reset(&mut self, now: f64) {
+        self.start_time = now;
     }
 
     pub fn get_elapsed_time(&self, now: f64) -> f64 {
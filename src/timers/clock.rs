@@ -0,0 +1,57 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::web_utils::window_time;
+
+/// A source of time used by the `GameTime` to measure frames.
+pub trait Clock {
+    /// Returns the time passed (ms) since the previous tick.
+    fn tick(&mut self) -> f64;
+}
+
+/// Browser clock, backed by the `performance.now()` API.
+pub struct WindowClock {
+    last_tick: f64,
+}
+
+impl WindowClock {
+    pub fn new() -> Self {
+        WindowClock {
+            last_tick: window_time(),
+        }
+    }
+}
+
+impl Clock for WindowClock {
+    fn tick(&mut self) -> f64 {
+        let now = window_time();
+        let elapsed = now - self.last_tick;
+
+        self.last_tick = now;
+
+        elapsed
+    }
+}
+
+/// A manually advanced clock, allowing the game to be stepped frame by frame outside the browser.
+/// Cloned handles share the same time.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock {
+    pending: Rc<Cell<f64>>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, delta: f64) {
+        self.pending.set(self.pending.get() + delta);
+    }
+}
+
+impl Clock for ManualClock {
+    fn tick(&mut self) -> f64 {
+        self.pending.replace(0.0)
+    }
+}
@@ -1,35 +1,59 @@
-use crate::timers::base_timer::Timer;
+use crate::timers::clock::Clock;
+
+/// Supported game speed multipliers.
+pub const GAME_SPEEDS: [f64; 4] = [0.5, 1.0, 2.0, 3.0];
 
 pub struct GameTime {
     pub time: f64,
     pub last_timestamp: f64,
+    pub frame_duration: f64,
+    pub paused: bool,
+    pub speed: f64,
 
-    timer: Timer,
+    clock: Box<dyn Clock>,
 }
 
 impl GameTime {
-    pub fn new() -> GameTime {
+    pub fn new(clock: Box<dyn Clock>) -> GameTime {
         GameTime {
             time: 0.0,
             last_timestamp: 0.0,
-            timer: Timer::new(1000.0),
+            frame_duration: 0.0,
+            paused: false,
+            speed: 1.0,
+            clock,
         }
     }
 
     pub fn start(&mut self) {
-        self.timer.start();
+        // Dismiss any time passed before the game started.
+        self.clock.tick();
     }
 
     pub fn current_time(&mut self) -> f64 {
-        // Tick between game frames.
-        let now = self.timer.get_current_time();
-        let elapsed = self.timer.get_elapsed_time(now);
+        // Tick between game frames, game time is scaled by the game speed.
+        let elapsed = self.clock.tick();
 
-        // Setting current time
-        self.time = self.last_timestamp + elapsed;
+        self.advance(elapsed * self.speed)
+    }
+
+    /// Advances by a frame duration recorded by a replay, rather than by the time the clock ticked meanwhile.
+    /// Recorded durations are already scaled by the game speed they were played at.
+    pub fn replay_frame(&mut self, frame_duration: f64) -> f64 {
+        self.clock.tick();
+
+        self.advance(frame_duration)
+    }
 
-        // Reset timer for next tick
-        self.timer.reset(None);
+    fn advance(&mut self, frame_duration: f64) -> f64 {
+        // Game time is frozen while paused
+        self.frame_duration = match self.paused {
+            true => 0.0,
+            false => frame_duration,
+        };
+
+        // Setting current time
+        self.time = self.last_timestamp + self.frame_duration;
 
         self.time
     }
@@ -37,4 +61,16 @@ impl GameTime {
     pub fn stamp(&mut self) {
         self.last_timestamp = self.time;
     }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Sets the game speed, falling back to normal speed for unsupported multipliers.
+    pub fn set_speed(&mut self, speed: f64) {
+        self.speed = match GAME_SPEEDS.contains(&speed) {
+            true => speed,
+            false => 1.0,
+        };
+    }
 }
@@ -1,5 +1,7 @@
 mod base_timer;
+mod clock;
 mod game_time;
 
 pub use base_timer::Timer;
-pub use game_time::GameTime;
+pub use clock::{Clock, ManualClock, WindowClock};
+pub use game_time::{GameTime, GAME_SPEEDS};
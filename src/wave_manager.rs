@@ -0,0 +1,178 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::features::GameFeatures;
+use crate::game::Game;
+use crate::location_builder::LocationBuilder;
+use crate::model::{BehaviorType, LevelData, WaveData};
+use crate::resource_loader::ResourceKind;
+use crate::sprite::{BehaviorManager, DrawingState, Sprite};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveState {
+    pub next_wave: usize,
+    pub last_spawned: f64,
+    pub killed_zombies: usize,
+}
+
+impl WaveState {
+    pub fn new() -> Self {
+        WaveState {
+            next_wave: 0,
+            last_spawned: 0.0,
+            killed_zombies: 0,
+        }
+    }
+}
+
+pub struct WaveManager;
+
+impl WaveManager {
+    pub fn tick(game: &mut Game) {
+        let now = game.game_time.time;
+
+        if !GameFeatures::should_spawn_waves() {
+            // Waves delays are counted from the moment spawning is enabled.
+            game.state.wave_state.last_spawned = now;
+            return;
+        }
+
+        let level = game.state.get_level();
+        let WaveState {
+            next_wave,
+            last_spawned,
+            ..
+        } = game.state.wave_state;
+
+        if let Some(wave) = level.waves.get(next_wave) {
+            if now - last_spawned >= wave.delay {
+                game.state.wave_state.next_wave += 1;
+                game.state.wave_state.last_spawned = now;
+
+                // Survival rounds keep on coming, none of them is final.
+                let remaining_waves = &level.waves[next_wave + 1..];
+                let is_final_wave = wave.flag
+                    && !remaining_waves.iter().any(|wave| wave.flag)
+                    && game.state.survival.is_none();
+
+                Self::spawn_wave(game, wave, is_final_wave);
+            }
+        }
+    }
+
+    /// Whether all level waves were already spawned.
+    pub fn all_waves_spawned(game: &Game) -> bool {
+        game.state.wave_state.next_wave >= game.state.get_level().waves.len()
+    }
+
+    /// The level progress (0 - 1) by killed zombies, where each flag wave closes an even part of it.
+    pub fn progress(game: &Game) -> f64 {
+        let segments = Self::segments(&game.state.get_level());
+
+        if segments.is_empty() {
+            return 0.0;
+        }
+
+        let mut killed = game.state.wave_state.killed_zombies;
+        let mut progress = 0.0;
+
+        for segment_size in segments.iter() {
+            if killed >= *segment_size {
+                killed -= segment_size;
+                progress += 1.0;
+            } else {
+                progress += killed as f64 / *segment_size as f64;
+                break;
+            }
+        }
+
+        progress / segments.len() as f64
+    }
+
+    /// The level progress points at which each of it's flag waves is cleared.
+    pub fn flag_marks(level: &LevelData) -> Vec<f64> {
+        let segments_count = Self::segments(level).len() as f64;
+        let flag_waves = level.waves.iter().filter(|wave| wave.flag).count();
+
+        (1..=flag_waves)
+            .map(|flag| flag as f64 / segments_count)
+            .collect()
+    }
+
+    /// Splits the level waves into segments ending with a flag wave, returns each segment zombies count.
+    fn segments(level: &LevelData) -> Vec<usize> {
+        let mut segments = vec![];
+        let mut segment_size = 0;
+
+        level.waves.iter().for_each(|wave| {
+            segment_size += wave.zombies.len() + wave.flag as usize;
+
+            if wave.flag {
+                segments.push(segment_size);
+                segment_size = 0;
+            }
+        });
+
+        if segment_size > 0 {
+            segments.push(segment_size);
+        }
+
+        segments
+    }
+
+    fn spawn_wave(game: &mut Game, wave: &WaveData, is_final_wave: bool) {
+        let now = game.game_time.time;
+
+        let mut zombie_names: Vec<&str> = wave.zombies.iter().map(|name| name.trim()).collect();
+
+        if wave.flag {
+            zombie_names.insert(0, "Flag");
+        }
+
+        let mut zombies = Sprite::create_sprites(
+            zombie_names,
+            &ResourceKind::Zombie,
+            &game.resources,
+            &mut game.random,
+        );
+
+        zombies.iter_mut().for_each(|zombie| {
+            let row = wave
+                .row
+                .unwrap_or_else(|| game.random.range(1.0, 5.0) as usize);
+
+            zombie.update_swap_cell(0);
+            zombie.attack_state.life += wave.extra_life;
+
+            let zombie_cell = DrawingState::get_active_cell(zombie);
+            zombie.update_position(LocationBuilder::zombie_location(
+                zombie_cell,
+                row,
+                &mut game.random,
+            ));
+
+            zombie.toggle_walking(true, now);
+        });
+
+        BehaviorManager::toggle_behaviors(
+            &zombies,
+            &[BehaviorType::Animate, BehaviorType::Collision],
+            true,
+            now,
+        );
+
+        game.add_sprites(zombies.as_mut());
+
+        if is_final_wave {
+            let mut final_wave = Sprite::create_sprite(
+                "FinalWave",
+                &ResourceKind::Interface,
+                &game.resources,
+                &mut game.random,
+            );
+
+            BehaviorManager::toggle_behaviors(&final_wave, &[BehaviorType::Animate], true, now);
+
+            game.add_sprites(final_wave.as_mut());
+        }
+    }
+}
@@ -2,6 +2,7 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
 
+#[cfg(target_arch = "wasm32")]
 #[macro_export]
 macro_rules! log {
     ($($t:tt)*) => {
@@ -9,6 +10,15 @@ macro_rules! log {
     }
 }
 
+/// Outside the browser there is no web console, logs are written into stdout.
+#[cfg(not(target_arch = "wasm32"))]
+#[macro_export]
+macro_rules! log {
+    ($($t:tt)*) => {
+        println!($($t)*)
+    }
+}
+
 /// "window" API Getters
 pub fn window() -> web_sys::Window {
     web_sys::window().expect("Global `window` is expected to be present.")
@@ -53,6 +63,18 @@ pub fn get_canvas_context(canvas: &HtmlCanvasElement) -> CanvasRenderingContext2
         .unwrap()
 }
 
+/// Reads a given query string parameter from the current page url.
+pub fn query_param(name: &str) -> Option<String> {
+    let search = window().location().search().ok()?;
+
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _value)| *key == name)
+        .map(|(_key, value)| value.to_string())
+}
+
 pub fn window_time() -> f64 {
     let performance = window()
         .performance()
@@ -0,0 +1,132 @@
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::{has_sprite, run_until_hit, start_battle, start_battle_from, zombie, ASSETS_PATH};
+use plants_vs_zombies::simulation::{Simulation, SpriteSnapshot, SpriteType};
+use serde_json::Value;
+
+/// A copy of the shipped assets where pea bullets are lobbed, as none of the shipped plants lobs yet.
+/// The copy is removed once dropped.
+struct LobbedAssets {
+    path: PathBuf,
+}
+
+impl LobbedAssets {
+    fn new() -> Self {
+        let path = std::env::temp_dir().join(format!("lobbed-assets-{}", std::process::id()));
+        let json_path = path.join("json");
+
+        fs::create_dir_all(&json_path).unwrap();
+
+        for entry in fs::read_dir(Path::new(ASSETS_PATH).join("json")).unwrap() {
+            let entry = entry.unwrap();
+            fs::copy(entry.path(), json_path.join(entry.file_name())).unwrap();
+        }
+
+        let plant_data_path = json_path.join("plant-data.json");
+        let mut plant_data: Value =
+            serde_json::from_str(&fs::read_to_string(&plant_data_path).unwrap()).unwrap();
+
+        plant_data["NormalBullet"]["trajectory"] = Value::from("Lobbed");
+        fs::write(&plant_data_path, plant_data.to_string()).unwrap();
+
+        LobbedAssets { path }
+    }
+}
+
+impl Drop for LobbedAssets {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[test]
+fn helmets_take_the_damage_first() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 2));
+    let conehead = simulation.spawn_zombie("Conehead", 3, 7);
+
+    run_until_hit(&mut simulation, &[&conehead], 175.0);
+
+    assert_eq!(zombie(&simulation, &conehead).armor, 75.0);
+    assert_eq!(zombie(&simulation, &conehead).life, 100.0);
+}
+
+#[test]
+fn broken_armor_falls_off() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 2));
+    let conehead = simulation.spawn_zombie("Conehead", 3, 7);
+
+    run_until_hit(&mut simulation, &[&conehead], 100.0);
+    assert!(!has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "ConeheadDrop"
+    ));
+
+    // The drop follows once the zombie is done taking the breaking hit.
+    simulation.run_for(100.0);
+    assert!(has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "ConeheadDrop"
+    ));
+
+    run_until_hit(&mut simulation, &[&conehead], 75.0);
+    assert_eq!(zombie(&simulation, &conehead).life, 75.0);
+}
+
+#[test]
+fn shields_block_chilling_projectiles() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("SnowPea", 3, 2));
+    let screen_door = simulation.spawn_zombie("ScreenDoor", 3, 7);
+    let zombie1 = simulation.spawn_zombie("Zombie1", 2, 7);
+    assert!(simulation.plant("SnowPea", 2, 2));
+
+    run_until_hit(&mut simulation, &[&screen_door], 200.0);
+    run_until_hit(&mut simulation, &[&zombie1], 75.0);
+
+    assert_eq!(zombie(&simulation, &screen_door).life, 100.0);
+    assert!(!zombie(&simulation, &screen_door).chilled);
+    assert!(zombie(&simulation, &zombie1).chilled);
+}
+
+#[test]
+fn lobbed_projectiles_pass_over_shields() {
+    let lobbed_assets = LobbedAssets::new();
+    let mut simulation = start_battle_from(&lobbed_assets.path);
+
+    assert!(simulation.plant("Peashooter", 3, 2));
+    let screen_door = simulation.spawn_zombie("ScreenDoor", 3, 7);
+    let SpriteSnapshot { life, armor, .. } = zombie(&simulation, &screen_door);
+
+    run_until_hit(&mut simulation, &[&screen_door], life + armor - 25.0);
+
+    assert_eq!(zombie(&simulation, &screen_door).armor, armor);
+    assert_eq!(zombie(&simulation, &screen_door).life, life - 25.0);
+}
+
+#[test]
+fn armor_is_kept_by_snapshots() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 2));
+    let buckethead = simulation.spawn_zombie("Buckethead", 3, 7);
+
+    run_until_hit(&mut simulation, &[&buckethead], 225.0);
+
+    let snapshot = simulation.snapshot().unwrap();
+
+    let mut restored = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    restored.restore(&snapshot);
+
+    assert_eq!(zombie(&restored, &buckethead).armor, 125.0);
+    assert_eq!(zombie(&restored, &buckethead).life, 100.0);
+}
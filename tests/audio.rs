@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::fs;
+
+use plants_vs_zombies::simulation::{
+    GameKeyEvent, LevelData, MemoryStorage, Music, Simulation, SoundEffect, SpriteType, WaveData,
+};
+
+mod common;
+
+use common::{click_sprite, ASSETS_PATH};
+
+#[test]
+fn keys_are_mapped_into_mute() {
+    assert_eq!(GameKeyEvent::from_key("m"), Some(GameKeyEvent::ToggleMute));
+    assert_eq!(GameKeyEvent::from_key("M"), Some(GameKeyEvent::ToggleMute));
+}
+
+#[test]
+fn each_scene_plays_its_own_music() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    assert_eq!(simulation.music(), Some(Music::Home));
+
+    simulation.start_level("1-1", 1);
+    simulation.run_for(5_000.0);
+
+    assert_eq!(simulation.music(), Some(Music::Chooser));
+
+    click_sprite(&mut simulation, &SpriteType::Seed, "Peashooter");
+    click_sprite(&mut simulation, &SpriteType::Interface, "OkButton");
+    simulation.run_for(8_000.0);
+
+    assert_eq!(simulation.music(), Some(Music::Battle));
+}
+
+#[test]
+fn music_stops_once_battle_is_over() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("1-1"),
+            plant_cards: vec![String::from("Peashooter")],
+            waves: vec![WaveData {
+                zombies: vec![String::from("Zombie1")],
+                row: Some(2),
+                ..WaveData::default()
+            }],
+            ..LevelData::default()
+        },
+        1,
+    );
+    simulation.step();
+
+    assert_eq!(simulation.music(), Some(Music::Battle));
+
+    assert!(simulation.plant("Peashooter", 2, 3));
+    assert!(simulation.plant("Peashooter", 2, 4));
+    simulation.run_for(40_000.0);
+
+    assert_eq!(simulation.completed_levels(), ["1-1"]);
+
+    assert_eq!(simulation.music(), None);
+}
+
+#[test]
+fn volume_settings_survive_reload() {
+    let storage = MemoryStorage::new();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+    simulation.set_volume(1.5);
+    simulation.key_event(GameKeyEvent::ToggleMute);
+
+    let reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+    let settings = &reloaded.save_data().settings;
+
+    assert_eq!(settings.volume, 1.0);
+    assert!(settings.muted);
+
+    simulation.set_volume(0.4);
+    simulation.key_event(GameKeyEvent::ToggleMute);
+
+    let settings = &simulation.save_data().settings;
+
+    assert_eq!(settings.volume, 0.4);
+    assert!(!settings.muted);
+}
+
+#[test]
+fn muting_is_not_recorded_into_replay() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", 1);
+    simulation.key_event(GameKeyEvent::ToggleMute);
+    simulation.step();
+
+    assert!(simulation.replay().unwrap().events.is_empty());
+    assert!(simulation.save_data().settings.muted);
+}
+
+#[test]
+fn sound_data_points_into_shipped_files() {
+    let sounds: HashMap<String, serde_json::Value> = serde_json::from_str(
+        &fs::read_to_string(format!("{}/json/sound-data.json", ASSETS_PATH)).unwrap(),
+    )
+    .unwrap();
+
+    let names = SoundEffect::ALL
+        .iter()
+        .map(|effect| effect.value())
+        .chain(Music::ALL.iter().map(|music| music.value()));
+
+    names.for_each(|name| {
+        let src = sounds
+            .get(name)
+            .and_then(|sound| sound["src"].as_str())
+            .unwrap_or_else(|| panic!("Sound {} is missing", name));
+
+        assert!(
+            fs::metadata(format!("{}{}", env!("CARGO_MANIFEST_DIR"), src)).is_ok(),
+            "Sound {} misses {}",
+            name,
+            src
+        );
+    });
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::{level, ASSETS_PATH};
+use plants_vs_zombies::simulation::{Simulation, SpriteType};
+
+fn alive(simulation: &Simulation, sprite_type: &SpriteType) -> usize {
+    simulation
+        .sprites(sprite_type)
+        .iter()
+        .filter(|sprite| sprite.visible && sprite.life > 0.0)
+        .count()
+}
+
+#[test]
+fn peashooter_defeats_conehead() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+    simulation.step();
+
+    assert!(simulation.plant("Peashooter", 1, 3));
+    assert_eq!(alive(&simulation, &SpriteType::Zombie), 1);
+
+    simulation.run_for(60_000.0);
+
+    assert_eq!(alive(&simulation, &SpriteType::Zombie), 0);
+    assert_eq!(alive(&simulation, &SpriteType::Plant), 1);
+}
+
+#[test]
+fn conehead_eats_undefended_wallnut() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+
+    assert!(simulation.plant("WallNut", 1, 6));
+
+    simulation.run_for(50_000.0);
+
+    assert_eq!(alive(&simulation, &SpriteType::Plant), 0);
+    assert_eq!(alive(&simulation, &SpriteType::Zombie), 1);
+}
+
+#[test]
+fn cannot_plant_on_occupied_cell() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&[]), 1);
+
+    assert!(simulation.plant("Peashooter", 2, 4));
+    assert!(!simulation.plant("WallNut", 2, 4));
+}
+
+fn zombies_layout(seed: u64) -> Vec<(String, f64, f64)> {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 7).unwrap();
+
+    simulation.start_battle(level(&["Conehead", "Buckethead", "Zombie1"]), seed);
+    simulation.run_for(20_000.0);
+
+    assert_eq!(simulation.seed(), seed);
+
+    [SpriteType::Zombie, SpriteType::Interface]
+        .iter()
+        .flat_map(|sprite_type| simulation.sprites(sprite_type))
+        .map(|sprite| (sprite.id, sprite.position.left, sprite.position.top))
+        .collect()
+}
+
+#[test]
+fn same_seed_reproduces_battle() {
+    assert_eq!(zombies_layout(42), zombies_layout(42));
+}
+
+#[test]
+fn different_seeds_change_battle() {
+    assert_ne!(zombies_layout(42), zombies_layout(43));
+}
+
+/// Runs a single zombie against the given plants, returning the zombie position, chill state and life per second.
+fn zombie_track(plants: &[(&str, usize)]) -> Vec<(f64, bool, f64)> {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Buckethead"]), 1);
+    simulation.step();
+
+    plants.iter().for_each(|(plant, col)| {
+        assert!(simulation.plant(plant, 1, *col));
+    });
+
+    (0..25)
+        .map(|_| {
+            simulation.run_for(1_000.0);
+
+            let zombie = simulation.sprites(&SpriteType::Zombie).remove(0);
+            (zombie.position.left, zombie.chilled, zombie.life)
+        })
+        .collect()
+}
+
+#[test]
+fn snow_pea_slows_zombie_down() {
+    let normal = zombie_track(&[("Peashooter", 2)]);
+    let chilled = zombie_track(&[("SnowPea", 2)]);
+
+    assert!(normal.iter().all(|(_, chilled, _)| !chilled));
+    assert!(chilled.iter().any(|(_, chilled, _)| *chilled));
+
+    // Chilled zombie walks a shorter distance
+    assert!(chilled.last().unwrap().0 > normal.last().unwrap().0);
+}
+
+#[test]
+fn torchwood_fire_cancels_snow_pea_chill() {
+    let track = zombie_track(&[("SnowPea", 2), ("Torchwood", 3)]);
+
+    assert!(track.iter().all(|(_, chilled, _)| !chilled));
+    assert!(track.last().unwrap().2 < track.first().unwrap().2);
+}
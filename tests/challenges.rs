@@ -0,0 +1,202 @@
+use plants_vs_zombies::simulation::{
+    GameMouseEvent, LevelData, Position, Simulation, SpriteType, WaveData,
+};
+
+mod common;
+
+use common::{click, sprite_point, ASSETS_PATH};
+
+fn count_sprites(simulation: &Simulation, sprite_type: &SpriteType, name: &str) -> usize {
+    simulation
+        .sprites(sprite_type)
+        .iter()
+        .filter(|sprite| sprite.name == name)
+        .count()
+}
+
+/// Starts the challenge, waiting for it's battle to begin.
+fn start_challenge(challenge_id: &str) -> Simulation {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level(challenge_id, 1);
+    simulation.run_for(13_000.0);
+
+    simulation
+}
+
+#[test]
+fn challenge_button_lists_challenges() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    let challenge_button = sprite_point(
+        &simulation,
+        &SpriteType::Interface,
+        "SelectorChallengeButton",
+    );
+    click(&mut simulation, challenge_button);
+
+    assert_eq!(
+        count_sprites(&simulation, &SpriteType::Interface, "ChallengeButton"),
+        4
+    );
+
+    let first_challenge = sprite_point(&simulation, &SpriteType::Interface, "ChallengeButton");
+    click(&mut simulation, first_challenge);
+
+    assert_eq!(simulation.level_id().as_deref(), Some("challenge-fixed"));
+}
+
+#[test]
+fn fixed_plants_skip_the_chooser() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("challenge-fixed", 1);
+    simulation.run_for(5_000.0);
+
+    assert!(simulation.sprites(&SpriteType::Seed).is_empty());
+    assert_eq!(
+        count_sprites(&simulation, &SpriteType::Interface, "OkButton"),
+        0
+    );
+
+    let cards = simulation
+        .sprites(&SpriteType::Card)
+        .into_iter()
+        .map(|card| card.name)
+        .collect::<Vec<String>>();
+
+    assert_eq!(cards, ["SunFlower", "Peashooter", "WallNut"]);
+
+    simulation.run_for(8_000.0);
+
+    assert_eq!(
+        count_sprites(&simulation, &SpriteType::Interface, "Shovel"),
+        1
+    );
+}
+
+#[test]
+fn sunless_challenge_drops_no_sun() {
+    let mut simulation = start_challenge("challenge-sunless");
+
+    assert_eq!(simulation.sun_score(), 400);
+
+    simulation.run_for(40_000.0);
+
+    assert_eq!(count_sprites(&simulation, &SpriteType::Interface, "Sun"), 0);
+}
+
+#[test]
+fn conveyor_delivers_free_single_use_cards() {
+    let mut simulation = start_challenge("challenge-conveyor");
+
+    let delivered = simulation.sprites(&SpriteType::Card).len();
+    simulation.run_for(12_000.0);
+
+    let cards = simulation.sprites(&SpriteType::Card);
+    assert_eq!(cards.len(), delivered + 2);
+    assert_eq!(count_sprites(&simulation, &SpriteType::Interface, "Sun"), 0);
+
+    // Planting is free, using up the card.
+    let card = sprite_point(&simulation, &SpriteType::Card, &cards[0].name);
+    let target = Position::new(270.0, 330.0);
+
+    simulation.mouse_event(GameMouseEvent::MouseMove, card);
+    simulation.step();
+    simulation.mouse_event(GameMouseEvent::MouseDown, card);
+    simulation.step();
+    simulation.mouse_event(GameMouseEvent::MouseMove, target);
+    simulation.step();
+    simulation.mouse_event(GameMouseEvent::MouseUp, target);
+    simulation.step();
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+    assert_eq!(simulation.sprites(&SpriteType::Card).len(), cards.len() - 1);
+    assert_eq!(simulation.sun_score(), 0);
+}
+
+#[test]
+fn conveyor_delivers_nothing_ahead_of_the_battle() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("challenge-conveyor", 1);
+    simulation.run_for(9_000.0);
+
+    assert!(simulation.sprites(&SpriteType::Card).is_empty());
+}
+
+#[test]
+fn conveyor_holds_limited_cards() {
+    let mut simulation = start_challenge("challenge-conveyor");
+
+    simulation.run_for(80_000.0);
+
+    assert_eq!(simulation.sprites(&SpriteType::Card).len(), 8);
+}
+
+#[test]
+fn last_stand_starts_with_a_planted_board() {
+    let simulation = start_challenge("challenge-last-stand");
+
+    let plants = simulation.sprites(&SpriteType::Plant);
+
+    assert_eq!(plants.len(), 7);
+    assert!(plants
+        .iter()
+        .any(|plant| plant.name == "SnowPea" && plant.row == 4 && plant.col == 3));
+    assert_eq!(simulation.sun_score(), 150);
+}
+
+#[test]
+fn challenges_are_reproducible_by_replay() {
+    let mut simulation = start_challenge("challenge-conveyor");
+    simulation.run_for(30_000.0);
+
+    let mut replayed = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    replayed.play(simulation.replay().unwrap());
+
+    let card_names = |simulation: &Simulation| {
+        simulation
+            .sprites(&SpriteType::Card)
+            .into_iter()
+            .map(|card| card.name)
+            .collect::<Vec<String>>()
+    };
+
+    assert_eq!(card_names(&replayed), card_names(&simulation));
+}
+
+#[test]
+fn won_challenges_return_to_the_challenge_select() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("challenge-fixed"),
+            waves: vec![WaveData {
+                zombies: vec![String::from("Zombie1")],
+                row: Some(2),
+                ..WaveData::default()
+            }],
+            ..LevelData::default()
+        },
+        1,
+    );
+    simulation.step();
+
+    assert!(simulation.plant("Peashooter", 2, 3));
+    assert!(simulation.plant("Peashooter", 2, 4));
+    simulation.run_for(40_000.0);
+
+    // Challenges take no part in the campaign progress.
+    assert!(simulation.completed_levels().is_empty());
+
+    let continue_button = sprite_point(&simulation, &SpriteType::Interface, "ContinueButton");
+    click(&mut simulation, continue_button);
+
+    assert_eq!(simulation.level_id(), None);
+    assert_eq!(
+        count_sprites(&simulation, &SpriteType::Interface, "ChallengeButton"),
+        4
+    );
+}
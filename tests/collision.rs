@@ -0,0 +1,62 @@
+mod common;
+
+use common::{plant_life, run_until_hit, start_battle, zombie_life};
+
+#[test]
+fn bullets_hit_the_nearest_zombie() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 2));
+    let near = simulation.spawn_zombie("Conehead", 3, 6);
+    let far = simulation.spawn_zombie("Conehead", 3, 7);
+
+    run_until_hit(&mut simulation, &[&near, &far], 175.0);
+
+    assert!(zombie_life(&simulation, &near) < 200.0);
+    assert_eq!(zombie_life(&simulation, &far), 200.0);
+}
+
+#[test]
+fn overlapping_zombies_are_hit_by_id() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 2));
+    let first = simulation.spawn_zombie("Conehead", 3, 7);
+    let second = simulation.spawn_zombie("Conehead", 3, 7);
+
+    run_until_hit(&mut simulation, &[&first, &second], 175.0);
+
+    let (hit, missed) = match first < second {
+        true => (first, second),
+        false => (second, first),
+    };
+
+    assert!(zombie_life(&simulation, &hit) < 200.0);
+    assert_eq!(zombie_life(&simulation, &missed), 200.0);
+}
+
+#[test]
+fn backward_bullets_hit_zombies_behind() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("SplitPea", 3, 6));
+    let zombie = simulation.spawn_zombie("Conehead", 3, 2);
+
+    run_until_hit(&mut simulation, &[&zombie], 175.0);
+
+    assert!(zombie_life(&simulation, &zombie) < 200.0);
+}
+
+#[test]
+fn zombies_eat_the_plant_in_front() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("SunFlower", 3, 3));
+    assert!(simulation.plant("WallNut", 3, 4));
+    simulation.spawn_zombie("Zombie1", 3, 5);
+
+    simulation.run_for(30_000.0);
+
+    assert!(plant_life(&simulation, 3, 4).unwrap_or_default() < 100.0);
+    assert_eq!(plant_life(&simulation, 3, 3), Some(100.0));
+}
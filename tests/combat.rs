@@ -0,0 +1,139 @@
+mod common;
+
+use common::{start_battle, ASSETS_PATH};
+use plants_vs_zombies::simulation::{Simulation, SpriteSnapshot, SpriteType};
+
+fn bullets(simulation: &Simulation) -> Vec<SpriteSnapshot> {
+    simulation.sprites(&SpriteType::Bullet)
+}
+
+/// Steps the simulation up to the plant first shot.
+fn run_until_shot(simulation: &mut Simulation) {
+    for _ in 0..600 {
+        simulation.step();
+
+        if !bullets(simulation).is_empty() {
+            return;
+        }
+    }
+
+    panic!("No shot was fired");
+}
+
+#[test]
+fn plants_shoot_zombies_ahead() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 4));
+    simulation.spawn_zombie("Conehead", 3, 9);
+
+    run_until_shot(&mut simulation);
+
+    assert_eq!(bullets(&simulation).len(), 1);
+    assert_eq!(bullets(&simulation)[0].row, 3);
+}
+
+#[test]
+fn plants_ignore_zombies_behind() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 6));
+    simulation.spawn_zombie("Conehead", 3, 3);
+
+    simulation.run_for(4_000.0);
+
+    assert!(bullets(&simulation).is_empty());
+}
+
+#[test]
+fn plants_ignore_other_rows() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 4));
+    simulation.spawn_zombie("Conehead", 2, 9);
+
+    simulation.run_for(4_000.0);
+
+    assert!(bullets(&simulation).is_empty());
+}
+
+#[test]
+fn repeater_staggers_its_projectiles() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Repeater", 3, 4));
+    simulation.spawn_zombie("Conehead", 3, 9);
+
+    run_until_shot(&mut simulation);
+    assert_eq!(bullets(&simulation).len(), 1);
+
+    simulation.run_for(200.0);
+
+    let bullets = bullets(&simulation);
+
+    assert_eq!(bullets.len(), 2);
+    assert!(bullets[0].position.left > bullets[1].position.left);
+}
+
+#[test]
+fn split_pea_shoots_backwards() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("SplitPea", 3, 6));
+    simulation.spawn_zombie("Conehead", 3, 3);
+
+    run_until_shot(&mut simulation);
+    simulation.run_for(200.0);
+
+    let first_positions = bullets(&simulation)
+        .iter()
+        .map(|bullet| bullet.position.left)
+        .collect::<Vec<f64>>();
+
+    // Two projectiles, backwards only, as there's nothing ahead.
+    assert_eq!(first_positions.len(), 2);
+
+    simulation.run_for(200.0);
+
+    bullets(&simulation)
+        .iter()
+        .zip(first_positions)
+        .for_each(|(bullet, first_position)| assert!(bullet.position.left < first_position));
+}
+
+#[test]
+fn threepeater_shoots_into_neighbor_rows() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Threepeater", 3, 4));
+    simulation.spawn_zombie("Conehead", 2, 9);
+    simulation.spawn_zombie("Conehead", 4, 9);
+
+    run_until_shot(&mut simulation);
+
+    let mut rows = bullets(&simulation)
+        .iter()
+        .map(|bullet| bullet.row)
+        .collect::<Vec<usize>>();
+    rows.sort();
+
+    assert_eq!(rows, vec![2, 4]);
+}
+
+#[test]
+fn staggered_projectiles_are_kept_by_snapshots() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Repeater", 3, 4));
+    simulation.spawn_zombie("Conehead", 3, 9);
+
+    run_until_shot(&mut simulation);
+
+    let snapshot = simulation.snapshot().unwrap();
+
+    let mut restored = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    restored.restore(&snapshot);
+    restored.run_for(200.0);
+
+    assert_eq!(bullets(&restored).len(), 2);
+}
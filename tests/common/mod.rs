@@ -0,0 +1,202 @@
+//! Fixtures shared by the integration tests, where each test file uses only some of them.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use plants_vs_zombies::simulation::{
+    GameMouseEvent, LevelData, Position, Simulation, SpriteSnapshot, SpriteType, WaveData,
+};
+
+pub const ASSETS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets");
+
+/// A wave of the given zombies, spawned after the given delay (ms).
+pub fn wave(delay: f64, zombies: &[&str]) -> WaveData {
+    WaveData {
+        delay,
+        zombies: zombies.iter().map(|zombie| zombie.to_string()).collect(),
+        ..WaveData::default()
+    }
+}
+
+/// A level of a single wave of the given zombies, all walking down the top row.
+pub fn level(zombies: &[&str]) -> LevelData {
+    LevelData {
+        name: String::from("simulation"),
+        waves: vec![WaveData {
+            row: Some(1),
+            ..wave(0.0, zombies)
+        }],
+        ..LevelData::default()
+    }
+}
+
+/// Starts a battle with a single far off wave keeping it going, zombies are spawned by each test.
+pub fn start_battle() -> Simulation {
+    start_battle_from(ASSETS_PATH)
+}
+
+/// Starts the same battle by the assets at the given path.
+pub fn start_battle_from(assets_path: impl AsRef<Path>) -> Simulation {
+    let mut simulation = Simulation::from_assets(assets_path, 1).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("simulation"),
+            flag_num: 1,
+            waves: vec![WaveData {
+                flag: true,
+                ..wave(120_000.0, &["Zombie1"])
+            }],
+            ..LevelData::default()
+        },
+        1,
+    );
+
+    simulation
+}
+
+/// Starts level 1-1 with the given plant chosen, waiting for the battle to begin.
+pub fn start_level_battle(plant_name: &str, seed: u64) -> Simulation {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", seed);
+    simulation.run_for(5_000.0);
+
+    click_sprite(&mut simulation, &SpriteType::Seed, plant_name);
+    click_sprite(&mut simulation, &SpriteType::Interface, "OkButton");
+
+    simulation.run_for(8_000.0);
+
+    simulation
+}
+
+/// Wins a level of the given id, defending a single zombie by planted Peashooters.
+pub fn win_level(simulation: &mut Simulation, level_id: &str) {
+    simulation.start_battle(
+        LevelData {
+            name: String::from(level_id),
+            plant_cards: vec![String::from("Peashooter"), String::from("SunFlower")],
+            waves: vec![WaveData {
+                zombies: vec![String::from("Zombie1")],
+                row: Some(2),
+                ..WaveData::default()
+            }],
+            ..LevelData::default()
+        },
+        1,
+    );
+    simulation.step();
+
+    assert!(simulation.plant("Peashooter", 2, 3));
+    assert!(simulation.plant("Peashooter", 2, 4));
+
+    simulation.run_for(40_000.0);
+}
+
+/// Whether a sprite of the given name is shown.
+pub fn has_sprite(simulation: &Simulation, sprite_type: &SpriteType, name: &str) -> bool {
+    simulation
+        .sprites(sprite_type)
+        .iter()
+        .any(|sprite| sprite.name == name)
+}
+
+/// The ids of the sprites of the given name, by their creation order.
+pub fn sprite_ids(simulation: &Simulation, sprite_type: &SpriteType, name: &str) -> Vec<String> {
+    simulation
+        .sprites(sprite_type)
+        .into_iter()
+        .filter(|sprite| sprite.name == name)
+        .map(|sprite| sprite.id)
+        .collect()
+}
+
+/// The zombie of the given id.
+pub fn zombie(simulation: &Simulation, id: &str) -> SpriteSnapshot {
+    simulation
+        .sprites(&SpriteType::Zombie)
+        .into_iter()
+        .find(|zombie| zombie.id == id)
+        .unwrap_or_else(|| panic!("Missing zombie {}", id))
+}
+
+/// Life left to the zombie of the given id, it's armor included.
+pub fn zombie_life(simulation: &Simulation, id: &str) -> f64 {
+    let zombie = zombie(simulation, id);
+
+    zombie.life + zombie.armor
+}
+
+/// The first plant of the given name, if any.
+pub fn plant(simulation: &Simulation, name: &str) -> Option<SpriteSnapshot> {
+    simulation
+        .sprites(&SpriteType::Plant)
+        .into_iter()
+        .find(|plant| plant.name == name)
+}
+
+/// The life of the plant at the given board cell, if any.
+pub fn plant_life(simulation: &Simulation, row: usize, col: usize) -> Option<f64> {
+    simulation
+        .sprites(&SpriteType::Plant)
+        .into_iter()
+        .find(|plant| plant.row == row && plant.col == col)
+        .map(|plant| plant.life)
+}
+
+/// Steps the simulation until any of the given zombies is hit down to the given life.
+pub fn run_until_hit(simulation: &mut Simulation, zombie_ids: &[&str], life: f64) {
+    for _ in 0..6_000 {
+        simulation.step();
+
+        if zombie_ids
+            .iter()
+            .any(|id| zombie_life(simulation, id) <= life)
+        {
+            return;
+        }
+    }
+
+    panic!("No zombie was hit");
+}
+
+/// A point within the given sprite, a bit off it's top left corner.
+pub fn sprite_point(simulation: &Simulation, sprite_type: &SpriteType, name: &str) -> Position {
+    let sprite = simulation
+        .sprites(sprite_type)
+        .into_iter()
+        .find(|sprite| sprite.name == name)
+        .unwrap_or_else(|| panic!("Missing sprite {}", name));
+
+    point_within(&sprite)
+}
+
+fn point_within(sprite: &SpriteSnapshot) -> Position {
+    Position::new(sprite.position.top + 10.0, sprite.position.left + 10.0)
+}
+
+/// Clicks the given position, releasing the mouse a frame later.
+pub fn click(simulation: &mut Simulation, position: Position) {
+    simulation.mouse_event(GameMouseEvent::MouseDown, position);
+    simulation.step();
+    simulation.mouse_event(GameMouseEvent::MouseUp, position);
+    simulation.step();
+}
+
+/// Clicks the first sprite of the given name.
+pub fn click_sprite(simulation: &mut Simulation, sprite_type: &SpriteType, name: &str) {
+    let position = sprite_point(simulation, sprite_type, name);
+
+    click(simulation, position);
+}
+
+/// Clicks the sprite of the given id.
+pub fn click_sprite_id(simulation: &mut Simulation, sprite_type: &SpriteType, id: &str) {
+    let sprite = simulation
+        .sprites(sprite_type)
+        .into_iter()
+        .find(|sprite| sprite.id == id)
+        .unwrap_or_else(|| panic!("Missing sprite {}", id));
+
+    click(simulation, point_within(&sprite));
+}
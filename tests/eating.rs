@@ -0,0 +1,95 @@
+mod common;
+
+use common::{plant, plant_life, start_battle};
+use plants_vs_zombies::simulation::{Simulation, SpriteType};
+
+/// Steps the simulation until the plant at the given board cell is bitten below the given life.
+fn run_until_bitten(simulation: &mut Simulation, row: usize, col: usize, life: f64) {
+    for _ in 0..3_000 {
+        simulation.step();
+
+        if plant_life(simulation, row, col).unwrap_or_default() < life {
+            return;
+        }
+    }
+
+    panic!("Plant at {}, {} was not bitten", row, col);
+}
+
+#[test]
+fn zombies_eat_by_their_damage_per_second() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("SunFlower", 3, 4));
+    simulation.spawn_zombie("Zombie1", 3, 5);
+
+    run_until_bitten(&mut simulation, 3, 4, 100.0);
+    let life = plant_life(&simulation, 3, 4).unwrap();
+
+    // Short of the 11th bite
+    simulation.run_for(10_500.0);
+
+    // 5 damage per second, taken by a bite each second.
+    assert_eq!(life - plant_life(&simulation, 3, 4).unwrap(), 50.0);
+}
+
+#[test]
+fn eating_damage_is_independent_of_the_framerate() {
+    let mut normal = start_battle();
+    let mut fast = start_battle();
+    fast.set_speed(3.0);
+
+    for simulation in [&mut normal, &mut fast] {
+        assert!(simulation.plant("WallNut", 3, 4));
+        simulation.spawn_zombie("Zombie1", 3, 5);
+
+        run_until_bitten(simulation, 3, 4, 100.0);
+    }
+
+    let bitten_at = (normal.game_time(), fast.game_time());
+
+    while normal.game_time() - bitten_at.0 < 8_000.0 {
+        normal.step();
+    }
+
+    while fast.game_time() - bitten_at.1 < 8_000.0 {
+        fast.step();
+    }
+
+    assert_eq!(plant_life(&normal, 3, 4), plant_life(&fast, 3, 4));
+}
+
+#[test]
+fn chilled_zombies_eat_slower() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("SnowPea", 3, 2));
+    assert!(simulation.plant("SunFlower", 3, 4));
+    simulation.spawn_zombie("Conehead", 3, 5);
+
+    run_until_bitten(&mut simulation, 3, 4, 100.0);
+    let life = plant_life(&simulation, 3, 4).unwrap();
+
+    simulation.run_for(10_500.0);
+
+    // Half of the 7.5 damage per second.
+    assert!(simulation.sprites(&SpriteType::Zombie)[0].chilled);
+    assert!(life - plant_life(&simulation, 3, 4).unwrap() <= 7.5 * 5.0);
+}
+
+#[test]
+fn wall_nuts_crack_as_they_are_eaten() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 4));
+    simulation.spawn_zombie("Zombie1", 3, 5);
+
+    run_until_bitten(&mut simulation, 3, 4, 100.0);
+    assert_eq!(plant(&simulation, "WallNut").unwrap().swap_index, None);
+
+    run_until_bitten(&mut simulation, 3, 4, 66.0);
+    assert_eq!(plant(&simulation, "WallNut").unwrap().swap_index, Some(0));
+
+    run_until_bitten(&mut simulation, 3, 4, 33.0);
+    assert_eq!(plant(&simulation, "WallNut").unwrap().swap_index, Some(1));
+}
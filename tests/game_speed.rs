@@ -0,0 +1,116 @@
+use plants_vs_zombies::simulation::{Simulation, SpriteType};
+
+mod common;
+
+use common::{click_sprite, has_sprite, level, ASSETS_PATH};
+
+fn zombie_left(simulation: &Simulation) -> f64 {
+    simulation.sprites(&SpriteType::Zombie)[0].position.left
+}
+
+fn has_paused_sign(simulation: &Simulation) -> bool {
+    has_sprite(simulation, &SpriteType::Interface, "PausedSign")
+}
+
+#[test]
+fn pause_freezes_the_battle() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+    simulation.run_for(5_000.0);
+
+    simulation.toggle_pause();
+    simulation.step();
+
+    let game_time = simulation.game_time();
+    let zombie_left_before = zombie_left(&simulation);
+    let sun_score = simulation.sun_score();
+
+    simulation.run_for(30_000.0);
+
+    assert!(has_paused_sign(&simulation));
+    assert_eq!(simulation.game_time(), game_time);
+    assert_eq!(zombie_left(&simulation), zombie_left_before);
+    assert_eq!(simulation.sun_score(), sun_score);
+
+    simulation.toggle_pause();
+    simulation.run_for(5_000.0);
+
+    assert!(!has_paused_sign(&simulation));
+    assert!(simulation.game_time() > game_time);
+    assert!(zombie_left(&simulation) < zombie_left_before);
+}
+
+#[test]
+fn speed_scales_game_time() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+    simulation.step();
+
+    let start = simulation.game_time();
+
+    simulation.set_speed(2.0);
+    simulation.run_for(1_000.0);
+
+    assert!((simulation.game_time() - start - 2_000.0).abs() < 50.0);
+}
+
+#[test]
+fn unsupported_speed_falls_back_to_normal() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+    simulation.step();
+
+    let start = simulation.game_time();
+
+    simulation.set_speed(7.0);
+    simulation.run_for(1_000.0);
+
+    assert!((simulation.game_time() - start - 1_000.0).abs() < 50.0);
+}
+
+#[test]
+fn pause_button_pauses_and_resumes_the_battle() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+    simulation.run_for(5_000.0);
+
+    click_sprite(&mut simulation, &SpriteType::Interface, "PauseButton");
+
+    let game_time = simulation.game_time();
+    simulation.run_for(5_000.0);
+
+    assert!(has_paused_sign(&simulation));
+    assert_eq!(simulation.game_time(), game_time);
+
+    click_sprite(&mut simulation, &SpriteType::Interface, "PauseButton");
+    simulation.run_for(5_000.0);
+
+    assert!(!has_paused_sign(&simulation));
+    assert!(simulation.game_time() > game_time);
+}
+
+#[test]
+fn speed_button_cycles_through_game_speeds() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(level(&["Conehead"]), 1);
+    simulation.step();
+
+    for expected_speed in [2.0, 3.0, 0.5, 1.0] {
+        click_sprite(&mut simulation, &SpriteType::Interface, "SpeedButton");
+
+        let start = simulation.game_time();
+        simulation.run_for(1_000.0);
+
+        let elapsed = simulation.game_time() - start;
+        assert!(
+            (elapsed - 1_000.0 * expected_speed).abs() < 50.0,
+            "{}",
+            elapsed
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use plants_vs_zombies::simulation::{GameKeyEvent, GameMouseEvent, Position, Simulation, SpriteType};
+
+mod common;
+
+use common::{click, start_level_battle, ASSETS_PATH};
+
+const TARGET: Position = Position {
+    top: 270.0,
+    left: 330.0,
+};
+
+fn press(simulation: &mut Simulation, event: GameKeyEvent) {
+    simulation.key_event(event);
+    simulation.step();
+}
+
+/// Clicks the target, moving the mouse onto it first so the dragged sprite follows.
+fn click_target(simulation: &mut Simulation) {
+    simulation.mouse_event(GameMouseEvent::MouseMove, TARGET);
+    simulation.step();
+    click(simulation, TARGET);
+}
+
+fn plant_by_keyboard(simulation: &mut Simulation) {
+    press(simulation, GameKeyEvent::PickCard(0));
+    click_target(simulation);
+}
+
+#[test]
+fn keys_are_mapped_into_game_events() {
+    assert_eq!(GameKeyEvent::from_key("1"), Some(GameKeyEvent::PickCard(0)));
+    assert_eq!(GameKeyEvent::from_key("9"), Some(GameKeyEvent::PickCard(8)));
+    assert_eq!(GameKeyEvent::from_key("s"), Some(GameKeyEvent::PickShovel));
+    assert_eq!(
+        GameKeyEvent::from_key("Escape"),
+        Some(GameKeyEvent::CancelDrag)
+    );
+    assert_eq!(GameKeyEvent::from_key("P"), Some(GameKeyEvent::TogglePause));
+    assert_eq!(GameKeyEvent::from_key("0"), None);
+    assert_eq!(GameKeyEvent::from_key("x"), None);
+}
+
+#[test]
+fn number_key_picks_plant_card() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+    let sun_score = simulation.sun_score();
+
+    plant_by_keyboard(&mut simulation);
+
+    let plants = simulation.sprites(&SpriteType::Plant);
+
+    assert_eq!(plants.len(), 1, "{:?}", plants);
+    assert_eq!(plants[0].name, "Peashooter");
+    assert!(simulation.sun_score() < sun_score);
+}
+
+#[test]
+fn number_key_without_card_is_ignored() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+
+    press(&mut simulation, GameKeyEvent::PickCard(5));
+    click_target(&mut simulation);
+
+    assert!(simulation.sprites(&SpriteType::Plant).is_empty());
+}
+
+#[test]
+fn escape_cancels_dragged_plant() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+    let sun_score = simulation.sun_score();
+
+    press(&mut simulation, GameKeyEvent::PickCard(0));
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+
+    press(&mut simulation, GameKeyEvent::CancelDrag);
+    click_target(&mut simulation);
+
+    assert!(simulation.sprites(&SpriteType::Plant).is_empty());
+    assert!(simulation.sun_score() >= sun_score);
+}
+
+#[test]
+fn shovel_key_digs_plant() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+
+    plant_by_keyboard(&mut simulation);
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+
+    press(&mut simulation, GameKeyEvent::PickShovel);
+    click_target(&mut simulation);
+
+    assert!(simulation.sprites(&SpriteType::Plant).is_empty());
+}
+
+#[test]
+fn escape_cancels_shovel_without_digging() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+
+    plant_by_keyboard(&mut simulation);
+
+    press(&mut simulation, GameKeyEvent::PickShovel);
+    press(&mut simulation, GameKeyEvent::CancelDrag);
+    click_target(&mut simulation);
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+}
+
+#[test]
+fn pause_key_toggles_pause() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+
+    press(&mut simulation, GameKeyEvent::TogglePause);
+
+    let game_time = simulation.game_time();
+    simulation.run_for(5_000.0);
+
+    assert_eq!(simulation.game_time(), game_time);
+
+    press(&mut simulation, GameKeyEvent::TogglePause);
+    simulation.run_for(5_000.0);
+
+    assert!(simulation.game_time() > game_time);
+}
+
+#[test]
+fn pause_key_is_ignored_outside_battle() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", 7);
+    press(&mut simulation, GameKeyEvent::TogglePause);
+
+    let game_time = simulation.game_time();
+    simulation.run_for(1_000.0);
+
+    assert!(simulation.game_time() > game_time);
+}
+
+#[test]
+fn paused_keys_are_not_recorded() {
+    let mut simulation = start_level_battle("Peashooter", 7);
+
+    press(&mut simulation, GameKeyEvent::TogglePause);
+
+    let events = simulation.replay().unwrap().events.len();
+
+    press(&mut simulation, GameKeyEvent::PickCard(0));
+    press(&mut simulation, GameKeyEvent::PickShovel);
+
+    assert_eq!(simulation.replay().unwrap().events.len(), events);
+
+    // The resuming key is kept, so the replay is paused for as long
+    press(&mut simulation, GameKeyEvent::TogglePause);
+
+    assert_eq!(simulation.replay().unwrap().events.len(), events + 1);
+}
+
+#[test]
+fn replay_reproduces_keyboard_events() {
+    let mut recorded = start_level_battle("Peashooter", 7);
+
+    plant_by_keyboard(&mut recorded);
+    press(&mut recorded, GameKeyEvent::TogglePause);
+    recorded.run_for(1_000.0);
+    press(&mut recorded, GameKeyEvent::TogglePause);
+    recorded.run_for(20_000.0);
+
+    let replay = recorded.replay().unwrap();
+
+    let mut replayed = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    replayed.play(replay.clone());
+
+    assert_eq!(replayed.replay().unwrap(), replay);
+    assert_eq!(replayed.game_time(), recorded.game_time());
+
+    for sprite_type in [SpriteType::Plant, SpriteType::Zombie, SpriteType::Bullet] {
+        assert_eq!(
+            format!("{:?}", recorded.sprites(&sprite_type)),
+            format!("{:?}", replayed.sprites(&sprite_type)),
+            "{:?} sprites differ",
+            sprite_type
+        );
+    }
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{plant, start_battle};
+use plants_vs_zombies::simulation::SpriteType;
+
+#[test]
+fn shells_wrap_plants() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 4));
+    assert!(simulation.plant("PumpkinHead", 3, 4));
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 2);
+}
+
+#[test]
+fn plants_are_placed_into_shells() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("PumpkinHead", 3, 4));
+    assert!(simulation.plant("Peashooter", 3, 4));
+}
+
+#[test]
+fn cell_layers_hold_a_single_plant() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 4));
+    assert!(simulation.plant("PumpkinHead", 3, 4));
+
+    assert!(!simulation.plant("Peashooter", 3, 4));
+    assert!(!simulation.plant("PumpkinHead", 3, 4));
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 2);
+}
+
+#[test]
+fn zombies_eat_the_shell_first() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 4));
+    simulation.spawn_zombie("Zombie1", 3, 5);
+
+    // Wrapping the plant once it's being eaten
+    for _ in 0..3_000 {
+        simulation.step();
+
+        if plant(&simulation, "WallNut").is_none_or(|wall_nut| wall_nut.life < 100.0) {
+            break;
+        }
+    }
+
+    let life = plant(&simulation, "WallNut").unwrap().life;
+    assert!(life < 100.0);
+    assert!(simulation.plant("PumpkinHead", 3, 4));
+
+    // The plant is untouched for as long as it's shell stands.
+    for _ in 0..3_000 {
+        simulation.step();
+
+        if plant(&simulation, "PumpkinHead").is_none_or(|pumpkin| !pumpkin.visible) {
+            break;
+        }
+
+        assert_eq!(
+            plant(&simulation, "WallNut").map(|wall_nut| wall_nut.life),
+            Some(life)
+        );
+    }
+
+    assert!(plant(&simulation, "PumpkinHead").is_none_or(|pumpkin| !pumpkin.visible));
+}
+
+#[test]
+fn shovel_digs_the_top_layer_first() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 4));
+    assert!(simulation.plant("PumpkinHead", 3, 4));
+
+    simulation.dig(3, 4);
+
+    assert!(plant(&simulation, "PumpkinHead").is_none());
+    assert!(plant(&simulation, "WallNut").is_some());
+
+    simulation.dig(3, 4);
+
+    assert!(simulation.sprites(&SpriteType::Plant).is_empty());
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fs;
+
+use plants_vs_zombies::simulation::{Simulation, SpriteType};
+
+mod common;
+
+use common::{click_sprite, click_sprite_id, has_sprite, sprite_ids, win_level, ASSETS_PATH};
+
+#[test]
+fn winning_a_level_records_completion() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    win_level(&mut simulation, "won");
+
+    assert_eq!(simulation.completed_levels(), ["won"]);
+    assert!(has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "LevelWonBackground"
+    ));
+    assert!(has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "ContinueButton"
+    ));
+}
+
+#[test]
+fn continue_without_next_level_returns_home() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    win_level(&mut simulation, "won");
+
+    click_sprite(&mut simulation, &SpriteType::Interface, "ContinueButton");
+
+    assert!(has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "SelectorBackground"
+    ));
+    assert!(!has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "ContinueButton"
+    ));
+}
+
+#[test]
+fn continue_advances_to_next_campaign_level() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    win_level(&mut simulation, "1-1");
+
+    click_sprite(&mut simulation, &SpriteType::Interface, "ContinueButton");
+
+    assert_eq!(simulation.level_id().as_deref(), Some("1-2"));
+}
+
+#[test]
+fn level_select_unlocks_campaign_in_order() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    // Adventure button, followed by the zombie hand animation
+    click_sprite(
+        &mut simulation,
+        &SpriteType::Interface,
+        "SelectorAdventureButton",
+    );
+    simulation.run_for(3_000.0);
+
+    let level_buttons = sprite_ids(&simulation, &SpriteType::Interface, "LevelButton");
+    assert_eq!(level_buttons.len(), 5);
+
+    click_sprite_id(&mut simulation, &SpriteType::Interface, &level_buttons[1]);
+    assert_eq!(simulation.level_id(), None);
+
+    click_sprite_id(&mut simulation, &SpriteType::Interface, &level_buttons[0]);
+    assert_eq!(simulation.level_id().as_deref(), Some("1-1"));
+}
+
+#[test]
+fn campaign_levels_exist() {
+    let read_json = |name: &str| -> HashMap<String, serde_json::Value> {
+        let path = format!("{}/json/{}", ASSETS_PATH, name);
+
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    };
+
+    let levels = read_json("level-data.json");
+
+    read_json("campaign-data.json")
+        .values()
+        .flat_map(|campaign| campaign.as_array().unwrap().clone())
+        .for_each(|level_id| {
+            assert!(
+                levels.contains_key(level_id.as_str().unwrap()),
+                "Level {}",
+                level_id
+            );
+        });
+}
@@ -0,0 +1,106 @@
+use plants_vs_zombies::simulation::{GameMouseEvent, Position, Simulation, SpriteType};
+
+mod common;
+
+use common::{sprite_point, ASSETS_PATH};
+
+/// Dispatches a DOM pointer event the way the engine does.
+fn pointer(simulation: &mut Simulation, event_type: &str, position: Position) {
+    let event = GameMouseEvent::from_pointer_event(event_type).unwrap();
+
+    simulation.mouse_event(event, position);
+    simulation.step();
+}
+
+/// A touch tap, with no hovering pointer moving ahead of it.
+fn tap(simulation: &mut Simulation, position: Position) {
+    pointer(simulation, "pointerdown", position);
+    pointer(simulation, "pointerup", position);
+    pointer(simulation, "pointerleave", position);
+}
+
+/// Starts level 1-1 choosing a Peashooter by touch, waiting for the battle to begin.
+fn start_battle_by_touch() -> Simulation {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", 7);
+    simulation.run_for(5_000.0);
+
+    let seed_point = sprite_point(&simulation, &SpriteType::Seed, "Peashooter");
+    tap(&mut simulation, seed_point);
+
+    let ok_point = sprite_point(&simulation, &SpriteType::Interface, "OkButton");
+    tap(&mut simulation, ok_point);
+
+    simulation.run_for(8_000.0);
+
+    simulation
+}
+
+#[test]
+fn pointer_events_map_into_mouse_events() {
+    let mapped = |event_type| GameMouseEvent::from_pointer_event(event_type);
+
+    assert_eq!(mapped("pointerdown"), Some(GameMouseEvent::MouseDown));
+    assert_eq!(mapped("pointermove"), Some(GameMouseEvent::MouseMove));
+    assert_eq!(mapped("pointerup"), Some(GameMouseEvent::MouseUp));
+    assert_eq!(mapped("pointercancel"), Some(GameMouseEvent::MouseCancel));
+    assert_eq!(mapped("pointerleave"), Some(GameMouseEvent::MouseLeave));
+    assert_eq!(mapped("touchstart"), None);
+}
+
+#[test]
+fn touch_drags_plant_onto_board() {
+    let mut simulation = start_battle_by_touch();
+
+    let card_point = sprite_point(&simulation, &SpriteType::Card, "Peashooter");
+    let target = Position::new(270.0, 330.0);
+
+    pointer(&mut simulation, "pointerdown", card_point);
+    pointer(&mut simulation, "pointermove", target);
+    pointer(&mut simulation, "pointerup", target);
+
+    let plants = simulation.sprites(&SpriteType::Plant);
+
+    assert_eq!(plants.len(), 1, "{:?}", plants);
+    assert_eq!(plants[0].name, "Peashooter");
+}
+
+#[test]
+fn cancelled_touch_releases_drag() {
+    let mut simulation = start_battle_by_touch();
+
+    let card_point = sprite_point(&simulation, &SpriteType::Card, "Peashooter");
+
+    // Touch cancelled over the cards bar, out of the board, the dragged plant is dropped.
+    pointer(&mut simulation, "pointerdown", card_point);
+    pointer(&mut simulation, "pointercancel", card_point);
+
+    simulation.run_for(1_000.0);
+
+    assert!(simulation.sprites(&SpriteType::Plant).is_empty());
+}
+
+#[test]
+fn cancelled_touch_over_board_does_not_plant() {
+    let mut simulation = start_battle_by_touch();
+
+    let card_point = sprite_point(&simulation, &SpriteType::Card, "Peashooter");
+    let target = Position::new(270.0, 330.0);
+
+    // Unlike releasing, cancelling the touch over the board leaves it empty.
+    pointer(&mut simulation, "pointerdown", card_point);
+    pointer(&mut simulation, "pointermove", target);
+    pointer(&mut simulation, "pointercancel", target);
+
+    simulation.run_for(1_000.0);
+
+    assert!(simulation.sprites(&SpriteType::Plant).is_empty());
+
+    // The card is picked again once the touch is over.
+    pointer(&mut simulation, "pointerdown", card_point);
+    pointer(&mut simulation, "pointermove", target);
+    pointer(&mut simulation, "pointerup", target);
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::{click, start_level_battle, ASSETS_PATH};
+use plants_vs_zombies::simulation::{GameKeyEvent, GameMouseEvent, Position, Simulation, SpriteType};
+
+/// Picks the first card and drops it's plant at the given point.
+fn plant_card(simulation: &mut Simulation, target: Position) {
+    simulation.key_event(GameKeyEvent::PickCard(0));
+    simulation.step();
+    simulation.mouse_event(GameMouseEvent::MouseMove, target);
+    simulation.step();
+    click(simulation, target);
+}
+
+fn card_recharge(simulation: &Simulation) -> f64 {
+    simulation.sprites(&SpriteType::Card)[0].recharge
+}
+
+#[test]
+fn planting_starts_card_recharge() {
+    let mut simulation = start_level_battle("Peashooter", 1);
+
+    assert_eq!(card_recharge(&simulation), 0.0);
+
+    plant_card(&mut simulation, Position::new(270.0, 330.0));
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+    assert!(card_recharge(&simulation) > 0.9);
+
+    simulation.run_for(3_750.0);
+
+    let recharge = card_recharge(&simulation);
+    assert!(recharge > 0.4 && recharge < 0.6, "{}", recharge);
+}
+
+#[test]
+fn recharging_card_refuses_planting() {
+    let mut simulation = start_level_battle("Peashooter", 1);
+
+    plant_card(&mut simulation, Position::new(270.0, 330.0));
+    let sun_score = simulation.sun_score();
+
+    // Affordable, yet still recharging.
+    plant_card(&mut simulation, Position::new(270.0, 410.0));
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+    assert_eq!(simulation.sun_score(), sun_score);
+
+    simulation.run_for(7_500.0);
+
+    assert_eq!(card_recharge(&simulation), 0.0);
+
+    plant_card(&mut simulation, Position::new(270.0, 410.0));
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 2);
+}
+
+#[test]
+fn recharge_time_is_per_card() {
+    let mut simulation = start_level_battle("WallNut", 1);
+
+    plant_card(&mut simulation, Position::new(270.0, 330.0));
+    simulation.run_for(7_500.0);
+
+    // Wall-nut takes far longer than the Peashooter to recharge.
+    assert!(card_recharge(&simulation) > 0.5);
+
+    plant_card(&mut simulation, Position::new(270.0, 410.0));
+
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 1);
+}
+
+#[test]
+fn recharge_is_kept_by_snapshots() {
+    let mut simulation = start_level_battle("Peashooter", 1);
+
+    plant_card(&mut simulation, Position::new(270.0, 330.0));
+    simulation.run_for(1_000.0);
+
+    let snapshot = simulation.snapshot().unwrap();
+    let recharge = card_recharge(&simulation);
+
+    let mut restored = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    restored.restore(&snapshot);
+    restored.step();
+
+    assert!((card_recharge(&restored) - recharge).abs() < 0.01);
+
+    plant_card(&mut restored, Position::new(270.0, 410.0));
+
+    assert_eq!(restored.sprites(&SpriteType::Plant).len(), 1);
+}
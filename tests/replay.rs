@@ -0,0 +1,152 @@
+mod common;
+
+use common::{click, sprite_point, start_level_battle, win_level, ASSETS_PATH};
+use plants_vs_zombies::simulation::{
+    GameKeyEvent, GameMouseEvent, MemoryStorage, Position, Replay, Simulation, SpriteType,
+};
+
+/// Plays level 1-1 the way a player would, choosing a Peashooter and dragging it onto the board.
+fn record_battle(seed: u64) -> Simulation {
+    let mut simulation = start_level_battle("Peashooter", seed);
+
+    let card_point = sprite_point(&simulation, &SpriteType::Card, "Peashooter");
+    simulation.mouse_event(GameMouseEvent::MouseDown, card_point);
+    simulation.run_for(100.0);
+
+    let target = Position::new(270.0, 330.0);
+    simulation.mouse_event(GameMouseEvent::MouseMove, target);
+    simulation.run_for(100.0);
+    simulation.mouse_event(GameMouseEvent::MouseUp, target);
+
+    simulation.run_for(30_000.0);
+
+    simulation
+}
+
+#[test]
+fn recorded_battle_plants_through_mouse_events() {
+    let simulation = record_battle(7);
+
+    let plants = simulation.sprites(&SpriteType::Plant);
+
+    assert_eq!(plants.len(), 1, "{:?}", plants);
+    assert_eq!(plants[0].name, "Peashooter");
+}
+
+#[test]
+fn replay_reproduces_battle() {
+    let recorded = record_battle(7);
+    let replay = recorded.replay().unwrap();
+
+    let mut replayed = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    replayed.play(replay.clone());
+
+    assert_eq!(replayed.replay().unwrap(), replay);
+    assert_eq!(replayed.game_time(), recorded.game_time());
+
+    for sprite_type in [SpriteType::Plant, SpriteType::Zombie, SpriteType::Bullet] {
+        let recorded = recorded.sprites(&sprite_type);
+        let replayed = replayed.sprites(&sprite_type);
+
+        assert_eq!(
+            format!("{:?}", recorded),
+            format!("{:?}", replayed),
+            "{:?} sprites differ",
+            sprite_type
+        );
+    }
+}
+
+#[test]
+fn replay_survives_serialization() {
+    let replay = record_battle(3).replay().unwrap();
+
+    let json = replay.to_json();
+
+    assert_eq!(Replay::from_json(&json).unwrap(), replay);
+}
+
+#[test]
+fn steady_frames_are_recorded_at_once() {
+    let replay = record_battle(7).replay().unwrap();
+
+    assert!(replay.frame_count > 1_000);
+    assert!(replay.frames.len() <= 2, "{:?}", replay.frames);
+}
+
+#[test]
+fn finished_level_is_kept_for_playback() {
+    let storage = MemoryStorage::new();
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+
+    win_level(&mut simulation, "1-1");
+
+    let mut reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+
+    assert!(reloaded.play_last_replay());
+    assert_eq!(reloaded.level_id().as_deref(), Some("1-1"));
+}
+
+#[test]
+fn nothing_is_played_back_before_a_level_is_finished() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    assert!(!simulation.play_last_replay());
+}
+
+#[test]
+fn paused_mouse_events_are_not_recorded() {
+    let mut simulation = record_battle(7);
+
+    simulation.key_event(GameKeyEvent::TogglePause);
+    simulation.step();
+
+    let events = simulation.replay().unwrap().events.len();
+
+    click(&mut simulation, Position::new(270.0, 330.0));
+
+    assert_eq!(simulation.replay().unwrap().events.len(), events);
+}
+
+#[test]
+fn control_clicks_are_recorded_whole() {
+    let mut simulation = record_battle(7);
+
+    // The second pause click is taken while paused, resuming the battle.
+    for control in ["SpeedButton", "PauseButton", "PauseButton"] {
+        let events = simulation.replay().unwrap().events.len();
+
+        let control_point = sprite_point(&simulation, &SpriteType::Interface, control);
+        click(&mut simulation, control_point);
+
+        assert_eq!(
+            simulation.replay().unwrap().events.len(),
+            events + 2,
+            "{}",
+            control
+        );
+    }
+}
+
+#[test]
+fn replay_reproduces_battle_paused_by_clicks() {
+    let mut recorded = record_battle(7);
+
+    let pause_point = sprite_point(&recorded, &SpriteType::Interface, "PauseButton");
+    click(&mut recorded, pause_point);
+    recorded.run_for(5_000.0);
+    click(&mut recorded, pause_point);
+    recorded.run_for(5_000.0);
+
+    let replay = recorded.replay().unwrap();
+
+    let mut replayed = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    replayed.play(replay.clone());
+
+    assert_eq!(replayed.replay().unwrap(), replay);
+    assert_eq!(replayed.game_time(), recorded.game_time());
+    assert_eq!(
+        format!("{:?}", replayed.sprites(&SpriteType::Zombie)),
+        format!("{:?}", recorded.sprites(&SpriteType::Zombie))
+    );
+}
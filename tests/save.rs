@@ -0,0 +1,162 @@
+use plants_vs_zombies::simulation::{MemoryStorage, SaveData, Settings, Simulation, SpriteType};
+
+mod common;
+
+use common::{win_level, ASSETS_PATH};
+
+fn seed_names(simulation: &Simulation) -> Vec<String> {
+    simulation
+        .sprites(&SpriteType::Seed)
+        .into_iter()
+        .map(|seed| seed.name)
+        .collect()
+}
+
+#[test]
+fn fresh_game_has_no_progress() {
+    let simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    assert_eq!(simulation.save_data(), &SaveData::default());
+}
+
+#[test]
+fn progress_survives_reload() {
+    let storage = MemoryStorage::new();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+    win_level(&mut simulation, "1-1");
+
+    assert_eq!(simulation.completed_levels(), ["1-1"]);
+
+    let reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+    let save_data = reloaded.save_data();
+
+    assert_eq!(save_data.completed_levels, ["1-1"]);
+    assert_eq!(save_data.unlocked_plants, ["Peashooter", "SunFlower"]);
+}
+
+#[test]
+fn winning_again_keeps_progress_unique() {
+    let storage = MemoryStorage::new();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+    win_level(&mut simulation, "1-1");
+    win_level(&mut simulation, "1-1");
+
+    let reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+
+    assert_eq!(reloaded.completed_levels(), ["1-1"]);
+    assert_eq!(reloaded.save_data().unlocked_plants.len(), 2);
+}
+
+#[test]
+fn speed_setting_survives_reload() {
+    let storage = MemoryStorage::new();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+    simulation.set_speed(2.0);
+
+    let mut reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+    assert_eq!(reloaded.save_data().settings.speed, 2.0);
+
+    reloaded.step();
+    let start = reloaded.game_time();
+    reloaded.run_for(1_000.0);
+
+    assert!((reloaded.game_time() - start - 2_000.0).abs() < 50.0);
+}
+
+#[test]
+fn older_save_is_upgraded_with_defaults() {
+    let save_data = SaveData::from_json(r#"{ "version": 0, "completed_levels": ["1-1"] }"#).unwrap();
+
+    assert_eq!(save_data.version, SaveData::default().version);
+    assert_eq!(save_data.completed_levels, ["1-1"]);
+    assert!(save_data.unlocked_plants.is_empty());
+    assert_eq!(save_data.settings, Settings::default());
+}
+
+#[test]
+fn save_round_trips_through_json() {
+    let save_data = SaveData {
+        completed_levels: vec![String::from("1-1"), String::from("1-2")],
+        unlocked_plants: vec![String::from("Peashooter")],
+        settings: Settings {
+            speed: 3.0,
+            volume: 0.5,
+            muted: true,
+        },
+        ..SaveData::default()
+    };
+
+    let json = save_data.to_json();
+
+    assert_eq!(SaveData::from_json(&json).unwrap(), save_data);
+}
+
+#[test]
+fn unreadable_save_is_rejected() {
+    assert!(SaveData::from_json("{ not a save").is_err());
+}
+
+#[test]
+fn newer_save_is_rejected() {
+    assert!(SaveData::from_json(r#"{ "version": 99, "completed_levels": ["1-1"] }"#).is_err());
+}
+
+#[test]
+fn newer_save_is_not_overwritten() {
+    let mut storage = MemoryStorage::new();
+    let newer_save = SaveData {
+        version: SaveData::default().version + 1,
+        completed_levels: vec![String::from("1-1"), String::from("1-2")],
+        ..SaveData::default()
+    };
+    newer_save.store(&mut storage).unwrap();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+
+    // The newer progress cannot be read, neither is it lost by playing along.
+    assert_eq!(simulation.save_data(), &SaveData::default());
+
+    simulation.set_speed(2.0);
+    win_level(&mut simulation, "1-1");
+
+    assert_eq!(SaveData::stored_version(&storage), Some(newer_save.version));
+    assert!(SaveData::default().store(&mut storage).is_err());
+}
+
+#[test]
+fn chooser_offers_unlocked_plants_along_the_level_ones() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-2", 1);
+    simulation.run_for(5_000.0);
+
+    assert_eq!(seed_names(&simulation), ["SnowPea"]);
+
+    let storage = MemoryStorage::new();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+    win_level(&mut simulation, "1-1");
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+
+    simulation.start_level("1-2", 1);
+    simulation.run_for(5_000.0);
+
+    assert_eq!(
+        seed_names(&simulation),
+        ["Peashooter", "SunFlower", "SnowPea"]
+    );
+}
+
+#[test]
+fn survival_offers_its_own_plants() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("survival", 1);
+    simulation.run_for(5_000.0);
+
+    assert_eq!(seed_names(&simulation).len(), 10);
+}
@@ -0,0 +1,149 @@
+use plants_vs_zombies::simulation::{
+    BattleSnapshot, LevelData, MemoryStorage, Simulation, SpriteType, WaveData,
+};
+
+mod common;
+
+use common::{wave, ASSETS_PATH};
+
+/// A level of three waves, the last one being it's single flag wave.
+fn snapshot_level() -> LevelData {
+    LevelData {
+        name: String::from("snapshot"),
+        flag_num: 1,
+        waves: vec![
+            wave(2_000.0, &["Zombie1", "Conehead"]),
+            wave(8_000.0, &["Buckethead", "Zombie1"]),
+            WaveData {
+                flag: true,
+                ..wave(8_000.0, &["Conehead", "Zombie1", "Zombie1"])
+            },
+        ],
+        ..LevelData::default()
+    }
+}
+
+/// Starts a battle with a defended board, running it until zombies are mid way.
+fn battle_in_progress(storage: MemoryStorage) -> Simulation {
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage).unwrap();
+
+    simulation.start_battle(snapshot_level(), 5);
+    simulation.step();
+
+    for row in 1..=5 {
+        assert!(simulation.plant("Peashooter", row, 3));
+        assert!(simulation.plant("SnowPea", row, 4));
+    }
+    assert!(simulation.plant("SunFlower", 1, 2));
+
+    simulation.run_for(15_000.0);
+
+    simulation
+}
+
+fn assert_same_battle(left: &Simulation, right: &Simulation) {
+    assert_eq!(left.game_time(), right.game_time());
+    assert_eq!(left.sun_score(), right.sun_score());
+    assert_eq!(left.level_progress(), right.level_progress());
+
+    for sprite_type in [
+        SpriteType::Plant,
+        SpriteType::Zombie,
+        SpriteType::Bullet,
+        SpriteType::Interface,
+        SpriteType::LawnCleaner,
+    ] {
+        assert_eq!(
+            format!("{:?}", left.sprites(&sprite_type)),
+            format!("{:?}", right.sprites(&sprite_type)),
+            "{:?} sprites differ",
+            sprite_type
+        );
+    }
+}
+
+#[test]
+fn no_snapshot_outside_battle() {
+    let simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    assert!(simulation.snapshot().is_none());
+}
+
+#[test]
+fn restored_battle_continues_identically() {
+    let mut original = battle_in_progress(MemoryStorage::new());
+    let snapshot = original.snapshot().unwrap();
+
+    assert!(!original.sprites(&SpriteType::Zombie).is_empty());
+
+    let mut restored = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    restored.run_for(1_000.0);
+    restored.restore(&snapshot);
+
+    assert_same_battle(&original, &restored);
+
+    original.run_for(20_000.0);
+    restored.run_for(20_000.0);
+
+    assert_same_battle(&original, &restored);
+}
+
+#[test]
+fn snapshot_survives_serialization() {
+    let snapshot = battle_in_progress(MemoryStorage::new()).snapshot().unwrap();
+
+    let json = snapshot.to_json();
+
+    assert_eq!(BattleSnapshot::from_json(&json).unwrap(), snapshot);
+}
+
+#[test]
+fn paused_battle_continues_after_reload() {
+    let storage = MemoryStorage::new();
+
+    let mut original = battle_in_progress(storage.clone());
+    original.toggle_pause();
+    original.step();
+    original.toggle_pause();
+
+    let mut reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+
+    assert!(reloaded.continue_saved_battle());
+
+    original.run_for(10_000.0);
+    reloaded.run_for(10_000.0);
+
+    assert_same_battle(&original, &reloaded);
+}
+
+#[test]
+fn finished_battle_is_not_continued() {
+    let storage = MemoryStorage::new();
+
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+    simulation.start_battle(
+        LevelData {
+            name: String::from("1-1"),
+            waves: vec![WaveData {
+                row: Some(2),
+                ..wave(0.0, &["Zombie1"])
+            }],
+            ..LevelData::default()
+        },
+        1,
+    );
+    simulation.step();
+
+    assert!(simulation.plant("Peashooter", 2, 3));
+    assert!(simulation.plant("Peashooter", 2, 4));
+
+    simulation.toggle_pause();
+    simulation.toggle_pause();
+    simulation.run_for(40_000.0);
+
+    assert_eq!(simulation.completed_levels(), ["1-1"]);
+
+    let mut reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+
+    assert!(!reloaded.continue_saved_battle());
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use common::{click_sprite_id, wave, ASSETS_PATH};
+use plants_vs_zombies::simulation::{
+    LevelData, LevelRules, Simulation, SpriteSnapshot, SpriteType, SunData, SunSize, WaveData,
+};
+
+/// Starts a battle of the given sun economy, with a single far off wave keeping it going.
+fn start_battle(sun: SunData) -> Simulation {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("sun"),
+            flag_num: 1,
+            waves: vec![WaveData {
+                flag: true,
+                ..wave(120_000.0, &["Zombie1"])
+            }],
+            rules: LevelRules {
+                sun,
+                ..LevelRules::default()
+            },
+            ..LevelData::default()
+        },
+        1,
+    );
+
+    simulation
+}
+
+fn suns(simulation: &Simulation) -> Vec<SpriteSnapshot> {
+    simulation
+        .sprites(&SpriteType::Interface)
+        .into_iter()
+        .filter(|sprite| sprite.name == "Sun")
+        .collect()
+}
+
+fn collect(simulation: &mut Simulation, sun: &SpriteSnapshot) {
+    click_sprite_id(simulation, &SpriteType::Interface, &sun.id);
+}
+
+#[test]
+fn level_sets_starting_sun_and_drop_interval() {
+    let mut simulation = start_battle(SunData {
+        starting: 100,
+        interval: 2_000.0,
+        ..SunData::default()
+    });
+
+    assert_eq!(simulation.sun_score(), 100);
+
+    simulation.run_for(1_500.0);
+    assert!(suns(&simulation).is_empty());
+
+    simulation.run_for(1_000.0);
+    assert_eq!(suns(&simulation).len(), 1);
+}
+
+#[test]
+fn collected_sun_is_worth_the_level_value() {
+    let mut simulation = start_battle(SunData {
+        starting: 0,
+        interval: 1_000.0,
+        value: 40,
+        ..SunData::default()
+    });
+
+    simulation.run_for(1_100.0);
+
+    let sun = suns(&simulation).remove(0);
+    collect(&mut simulation, &sun);
+
+    assert_eq!(simulation.sun_score(), 40);
+}
+
+#[test]
+fn sun_variants_scale_the_sun_value() {
+    let mut simulation = start_battle(SunData {
+        starting: 0,
+        interval: 1_000.0,
+        sky_sun: SunSize::Big,
+        ..SunData::default()
+    });
+
+    simulation.run_for(1_100.0);
+
+    let sun = suns(&simulation).remove(0);
+    assert_eq!(sun.scale, SunSize::Big.scale());
+
+    collect(&mut simulation, &sun);
+
+    assert_eq!(simulation.sun_score(), 50);
+
+    let sun_data = SunData::default();
+
+    assert_eq!(SunSize::Small.sun_value(&sun_data), 15);
+    assert_eq!(SunSize::Normal.sun_value(&sun_data), 25);
+}
+
+#[test]
+fn night_levels_drop_no_sun() {
+    let mut simulation = start_battle(SunData {
+        interval: 1_000.0,
+        night: true,
+        ..SunData::default()
+    });
+
+    simulation.run_for(30_000.0);
+
+    assert!(suns(&simulation).is_empty());
+}
+
+#[test]
+fn landed_sun_fades_away_after_its_lifetime() {
+    let mut simulation = start_battle(SunData {
+        interval: 60_000.0,
+        lifetime: 3_000.0,
+        ..SunData::default()
+    });
+
+    simulation.run_for(61_000.0);
+    assert_eq!(suns(&simulation).len(), 1);
+
+    // Sky sun takes a while to land, it's lifetime counts from there on.
+    simulation.run_for(13_000.0);
+
+    let sun = suns(&simulation).remove(0);
+    assert_eq!(sun.alpha, 1.0);
+
+    // Fading along the end of it's lifetime.
+    simulation.run_for(1_000.0);
+
+    let sun = suns(&simulation).remove(0);
+    assert!(sun.alpha < 1.0 && sun.alpha > 0.0, "{}", sun.alpha);
+
+    simulation.run_for(1_000.0);
+
+    assert!(suns(&simulation).is_empty());
+    assert_eq!(simulation.battle_stats().missed_sun, 1);
+    assert_eq!(simulation.battle_stats().collected_sun, 0);
+}
+
+#[test]
+fn collected_sun_is_not_missed() {
+    let mut simulation = start_battle(SunData {
+        interval: 1_000.0,
+        lifetime: 1_000.0,
+        ..SunData::default()
+    });
+
+    simulation.run_for(1_100.0);
+
+    let sun = suns(&simulation).remove(0);
+    collect(&mut simulation, &sun);
+
+    assert_eq!(simulation.battle_stats().collected_sun, 1);
+    assert_eq!(simulation.battle_stats().missed_sun, 0);
+}
+
+#[test]
+fn sunflower_sun_lands_next_to_its_sunflower() {
+    let mut simulation = start_battle(SunData {
+        night: true,
+        lifetime: 2_000.0,
+        ..SunData::default()
+    });
+
+    assert!(simulation.plant("SunFlower", 2, 2));
+
+    simulation.run_for(11_000.0);
+    assert_eq!(suns(&simulation).len(), 1);
+
+    // Landed within a few seconds, then fading away uncollected.
+    simulation.run_for(8_000.0);
+
+    assert!(suns(&simulation).is_empty());
+    assert_eq!(simulation.battle_stats().missed_sun, 1);
+}
+
+#[test]
+fn sunflower_produces_level_sun() {
+    let mut simulation = start_battle(SunData {
+        night: true,
+        sunflower_sun: SunSize::Small,
+        ..SunData::default()
+    });
+
+    assert!(simulation.plant("SunFlower", 2, 2));
+
+    simulation.run_for(11_000.0);
+
+    let suns = suns(&simulation);
+
+    assert_eq!(suns.len(), 1);
+    assert_eq!(suns[0].scale, SunSize::Small.scale());
+}
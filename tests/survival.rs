@@ -0,0 +1,176 @@
+use plants_vs_zombies::simulation::{MemoryStorage, Simulation, SpriteType};
+
+mod common;
+
+use common::{click_sprite, has_sprite, ASSETS_PATH};
+
+/// Starts a survival run with a Peashooter chosen, waiting for the battle to begin.
+fn start_survival(simulation: &mut Simulation, seed: u64) {
+    simulation.start_level("survival", seed);
+    simulation.run_for(5_000.0);
+
+    click_sprite(simulation, &SpriteType::Seed, "Peashooter");
+    click_sprite(simulation, &SpriteType::Interface, "OkButton");
+
+    simulation.run_for(8_000.0);
+}
+
+/// Guards the whole lawn, so rounds are cleared.
+fn defend_lawn(simulation: &mut Simulation) {
+    (1..=5).for_each(|row| {
+        (2..=4).for_each(|col| assert!(simulation.plant("Peashooter", row, col)));
+    });
+}
+
+/// Runs until the round in progress is cleared.
+fn clear_round(simulation: &mut Simulation, round: usize) {
+    let mut elapsed = 0.0;
+
+    while simulation.survival_round() == Some(round) {
+        simulation.run_for(1_000.0);
+        elapsed += 1_000.0;
+
+        assert!(elapsed < 300_000.0, "Round {} is never cleared", round);
+    }
+}
+
+#[test]
+fn survival_button_starts_a_run() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    click_sprite(
+        &mut simulation,
+        &SpriteType::Interface,
+        "SelectorSurvivalButton",
+    );
+
+    assert_eq!(simulation.level_id().as_deref(), Some("survival"));
+    assert_eq!(simulation.survival_round(), Some(0));
+    assert_eq!(simulation.survival_score(), 0);
+    assert!(has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "SurvivalScore"
+    ));
+}
+
+#[test]
+fn adventure_levels_are_not_survival() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", 1);
+
+    assert_eq!(simulation.survival_round(), None);
+}
+
+#[test]
+fn cleared_round_offers_plants_reselection_keeping_the_board() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    start_survival(&mut simulation, 7);
+    defend_lawn(&mut simulation);
+    clear_round(&mut simulation, 0);
+
+    assert_eq!(simulation.survival_round(), Some(1));
+    assert_eq!(simulation.survival_score(), 5);
+    assert!(has_sprite(&simulation, &SpriteType::Interface, "OkButton"));
+    assert!(!has_sprite(
+        &simulation,
+        &SpriteType::Interface,
+        "LevelWonBackground"
+    ));
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 15);
+    assert!(simulation.sprites(&SpriteType::Card).is_empty());
+
+    // No waves are spawned while plants are chosen.
+    simulation.run_for(30_000.0);
+    assert!(simulation.sprites(&SpriteType::Zombie).is_empty());
+
+    click_sprite(&mut simulation, &SpriteType::Seed, "SunFlower");
+    click_sprite(&mut simulation, &SpriteType::Interface, "OkButton");
+
+    assert!(!has_sprite(&simulation, &SpriteType::Interface, "OkButton"));
+    assert!(has_sprite(&simulation, &SpriteType::Interface, "SunScore"));
+    assert_eq!(simulation.sprites(&SpriteType::Card).len(), 1);
+
+    simulation.run_for(25_000.0);
+
+    assert!(!simulation.sprites(&SpriteType::Zombie).is_empty());
+    assert_eq!(simulation.sprites(&SpriteType::Plant).len(), 15);
+}
+
+#[test]
+fn rounds_scale_in_zombies_count() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    start_survival(&mut simulation, 7);
+    defend_lawn(&mut simulation);
+    clear_round(&mut simulation, 0);
+
+    click_sprite(&mut simulation, &SpriteType::Interface, "OkButton");
+    simulation.run_for(20_000.0);
+
+    // The first wave of the second round brings an extra zombie.
+    let zombies = simulation
+        .sprites(&SpriteType::Zombie)
+        .iter()
+        .filter(|zombie| zombie.life > 0.0)
+        .count();
+
+    assert!(zombies >= 2, "{} zombies", zombies);
+}
+
+#[test]
+fn survival_runs_are_reproducible_by_seed() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    start_survival(&mut simulation, 3);
+    defend_lawn(&mut simulation);
+    simulation.run_for(60_000.0);
+
+    let replay = simulation.replay().unwrap();
+    assert_eq!(replay.level_id, "survival");
+
+    let mut replayed = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    replayed.play(replay);
+
+    assert_eq!(replayed.survival_round(), simulation.survival_round());
+    assert_eq!(replayed.survival_score(), simulation.survival_score());
+}
+
+#[test]
+fn best_score_is_kept_once_the_run_is_lost() {
+    let storage = MemoryStorage::new();
+    let mut simulation = Simulation::with_storage(ASSETS_PATH, 1, storage.clone()).unwrap();
+
+    start_survival(&mut simulation, 5);
+
+    let mut elapsed = 0.0;
+    while !has_sprite(&simulation, &SpriteType::Interface, "ZombiesWon") {
+        simulation.run_for(5_000.0);
+        elapsed += 5_000.0;
+
+        assert!(elapsed < 1_000_000.0, "Survival run is never lost");
+    }
+
+    let score = simulation.survival_score();
+    assert!(score > 0);
+
+    let mut reloaded = Simulation::with_storage(ASSETS_PATH, 2, storage).unwrap();
+
+    assert_eq!(reloaded.save_data().survival_best, score);
+
+    // The next run shows the best score by it's own.
+    start_survival(&mut reloaded, 6);
+
+    let survival_score = reloaded
+        .sprites(&SpriteType::Interface)
+        .into_iter()
+        .find(|sprite| sprite.name == "SurvivalScore")
+        .unwrap();
+
+    assert!(survival_score
+        .text
+        .unwrap()
+        .ends_with(&format!("Best: {}", score)));
+}
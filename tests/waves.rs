@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fs;
+
+use plants_vs_zombies::simulation::{LevelData, Simulation, SpriteType, WaveData};
+
+mod common;
+
+use common::{wave, ASSETS_PATH};
+
+fn zombie_names(simulation: &Simulation) -> Vec<String> {
+    simulation
+        .sprites(&SpriteType::Zombie)
+        .into_iter()
+        .map(|zombie| zombie.name)
+        .collect()
+}
+
+#[test]
+fn waves_spawn_after_their_delay() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("waves"),
+            flag_num: 1,
+            waves: vec![
+                wave(1_000.0, &["Zombie1"]),
+                WaveData {
+                    flag: true,
+                    ..wave(2_000.0, &["Conehead"])
+                },
+            ],
+            ..LevelData::default()
+        },
+        1,
+    );
+
+    simulation.run_for(500.0);
+    assert!(zombie_names(&simulation).is_empty());
+
+    simulation.run_for(1_000.0);
+    assert_eq!(zombie_names(&simulation), vec!["Zombie1"]);
+
+    simulation.run_for(2_000.0);
+
+    let mut names = zombie_names(&simulation);
+    names.sort();
+
+    // Flag waves are lead by a Flag zombie
+    assert_eq!(names, vec!["Conehead", "Flag", "Zombie1"]);
+}
+
+#[test]
+fn pinned_wave_spawns_in_its_row() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 5).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("pinned"),
+            waves: vec![WaveData {
+                row: Some(4),
+                ..wave(0.0, &["Zombie1", "Conehead", "Buckethead"])
+            }],
+            ..LevelData::default()
+        },
+        5,
+    );
+    simulation.step();
+
+    let zombies = simulation.sprites(&SpriteType::Zombie);
+
+    assert_eq!(zombies.len(), 3);
+    assert!(zombies.iter().all(|zombie| zombie.row == 4));
+}
+
+#[test]
+fn no_zombies_spawn_before_battle_starts() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", 1);
+    simulation.run_for(30_000.0);
+
+    assert!(zombie_names(&simulation).is_empty());
+}
+
+#[test]
+fn levels_flag_waves_match_flag_num() {
+    let levels: HashMap<String, LevelData> = serde_json::from_str(
+        &fs::read_to_string(format!("{}/json/level-data.json", ASSETS_PATH)).unwrap(),
+    )
+    .unwrap();
+
+    levels.values().for_each(|level| {
+        let flag_waves = level.waves.iter().filter(|wave| wave.flag).count();
+
+        assert_eq!(flag_waves, level.flag_num, "Level {}", level.name);
+        assert!(
+            level.waves.last().is_some_and(|wave| wave.flag),
+            "Level {}",
+            level.name
+        );
+    });
+}
+
+#[test]
+fn wave_rows_off_the_board_are_rejected() {
+    let wave = |row: usize| {
+        serde_json::from_str::<WaveData>(&format!(
+            r#"{{ "delay": 0, "zombies": ["Zombie1"], "row": {} }}"#,
+            row
+        ))
+    };
+
+    assert_eq!(wave(5).unwrap().row, Some(5));
+    assert!(wave(0).is_err());
+    assert!(wave(6).is_err());
+}
+
+#[test]
+fn flag_meter_marks_level_flags() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_level("1-1", 1);
+
+    let flags = simulation
+        .sprites(&SpriteType::Interface)
+        .into_iter()
+        .filter(|sprite| sprite.name == "FlagMeterParts2")
+        .count();
+
+    assert_eq!(flags, 3);
+}
+
+#[test]
+fn flag_meter_fills_as_zombies_die() {
+    let mut simulation = Simulation::from_assets(ASSETS_PATH, 1).unwrap();
+
+    simulation.start_battle(
+        LevelData {
+            name: String::from("progress"),
+            flag_num: 1,
+            waves: vec![
+                WaveData {
+                    row: Some(1),
+                    ..wave(0.0, &["Zombie1"])
+                },
+                WaveData {
+                    row: Some(1),
+                    flag: true,
+                    ..wave(0.0, &["Zombie1"])
+                },
+            ],
+            ..LevelData::default()
+        },
+        1,
+    );
+    simulation.step();
+
+    assert!(simulation.plant("Peashooter", 1, 3));
+    assert!(simulation.plant("Peashooter", 1, 4));
+    assert_eq!(simulation.level_progress(), 0.0);
+
+    let mut progress = vec![];
+
+    (0..40).for_each(|_| {
+        simulation.run_for(1_000.0);
+        progress.push(simulation.level_progress());
+    });
+
+    assert!(progress.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(progress.iter().any(|value| *value > 0.0 && *value < 1.0));
+    assert_eq!(progress.last(), Some(&1.0));
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::{plant_life, start_battle, zombie, ASSETS_PATH};
+use plants_vs_zombies::simulation::Simulation;
+
+/// Steps the simulation until the zombie walks into the given column.
+fn run_until_col(simulation: &mut Simulation, id: &str, col: usize) {
+    for _ in 0..12_000 {
+        simulation.step();
+
+        if zombie(simulation, id).col <= col {
+            return;
+        }
+    }
+
+    panic!("Zombie did not reach column {}", col);
+}
+
+#[test]
+fn vaulters_leap_over_the_first_plant() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 6));
+    assert!(simulation.plant("WallNut", 3, 3));
+    let wall_nut_life = plant_life(&simulation, 3, 6).unwrap();
+
+    let vaulter = simulation.spawn_zombie("PoleVaulting", 3, 9);
+
+    run_until_col(&mut simulation, &vaulter, 5);
+    assert_eq!(plant_life(&simulation, 3, 6), Some(wall_nut_life));
+
+    // Once landed, the next plant is eaten as usual.
+    simulation.run_for(10_000.0);
+
+    assert!(zombie(&simulation, &vaulter).col > 3);
+    assert!(plant_life(&simulation, 3, 3).unwrap() < wall_nut_life);
+}
+
+#[test]
+fn row_switching_zombies_leap_into_a_neighbour_row() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 6));
+    assert!(simulation.plant("WallNut", 5, 6));
+    let wall_nut_life = plant_life(&simulation, 3, 6).unwrap();
+
+    let snorkel = simulation.spawn_zombie("Snorkel", 3, 8);
+    let bottom_snorkel = simulation.spawn_zombie("Snorkel", 5, 8);
+
+    simulation.run_for(20_000.0);
+
+    assert_eq!(zombie(&simulation, &snorkel).row, 4);
+    assert_eq!(zombie(&simulation, &bottom_snorkel).row, 4);
+    assert_eq!(plant_life(&simulation, 3, 6), Some(wall_nut_life));
+    assert_eq!(plant_life(&simulation, 5, 6), Some(wall_nut_life));
+}
+
+#[test]
+fn diggers_tunnel_to_the_back_column() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("Peashooter", 3, 5));
+    let digger = simulation.spawn_zombie("BackupDancer", 3, 9);
+
+    // Tunneling zombies are neither hit nor do they bite.
+    simulation.run_for(4_000.0);
+
+    assert!(zombie(&simulation, &digger).col > 2);
+    assert_eq!(zombie(&simulation, &digger).life, 100.0);
+
+    run_until_col(&mut simulation, &digger, 2);
+
+    assert_eq!(zombie(&simulation, &digger).life, 100.0);
+    assert!(plant_life(&simulation, 3, 5).is_some());
+}
+
+#[test]
+fn surfaced_diggers_eat_the_plants_from_behind() {
+    let mut simulation = start_battle();
+
+    assert!(simulation.plant("WallNut", 3, 4));
+    let wall_nut_life = plant_life(&simulation, 3, 4).unwrap();
+    let digger = simulation.spawn_zombie("BackupDancer", 3, 9);
+
+    run_until_col(&mut simulation, &digger, 2);
+    simulation.run_for(2_000.0);
+    let surfaced_at = zombie(&simulation, &digger).position.left;
+
+    // Surfaced zombies walk back along their row, into the plants ahead of the back column.
+    simulation.run_for(5_000.0);
+    assert!(zombie(&simulation, &digger).position.left > surfaced_at);
+
+    simulation.run_for(15_000.0);
+    assert!(plant_life(&simulation, 3, 4).unwrap() < wall_nut_life);
+    let eating_at = zombie(&simulation, &digger).position.left;
+
+    // Once eaten through, they carry on toward the back of the board.
+    simulation.run_for(20_000.0);
+    assert_eq!(plant_life(&simulation, 3, 4), None);
+    assert!(zombie(&simulation, &digger).position.left > eating_at);
+}
+
+#[test]
+fn leaps_are_kept_by_snapshots() {
+    let mut simulation = start_battle();
+
+    let digger = simulation.spawn_zombie("BackupDancer", 3, 9);
+    simulation.run_for(4_000.0);
+
+    let snapshot = simulation.snapshot().unwrap();
+
+    let mut restored = Simulation::from_assets(ASSETS_PATH, 2).unwrap();
+    restored.restore(&snapshot);
+
+    let col = zombie(&restored, &digger).col;
+    assert!(col > 2);
+
+    restored.run_for(4_500.0);
+
+    assert_eq!(zombie(&restored, &digger).col, 2);
+}